@@ -0,0 +1,522 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A deterministic finite automaton over integer values, ready to be
+/// given to [`CpModelBuilder::add_automaton`][crate::builder::CpModelBuilder::add_automaton].
+///
+/// It is usually built from a regex-like pattern with
+/// [`Automaton::from_pattern`] (or [`str::parse`]). The pattern
+/// describes the sequences of values accepted by the automaton:
+///
+/// - an integer, e.g. `3` or `-1`, matches this value;
+/// - `[0 2 5..=7]` matches any of the listed values or inclusive ranges;
+/// - `a b` matches `a` followed by `b`;
+/// - `a | b` matches `a` or `b`;
+/// - `a*`, `a+` and `a?` match `a` any number of times, at least once,
+///   and at most once;
+/// - `a{n}`, `a{n,}` and `a{n,m}` match `a` exactly `n` times, at least
+///   `n` times, and between `n` and `m` times;
+/// - parentheses group sub-patterns.
+///
+/// As every accepted value becomes a transition of the automaton, a
+/// set can contain at most 65536 values, and patterns that are too
+/// large once their repetitions are expanded, or whose deterministic
+/// automaton has too many states or transitions, are rejected.
+///
+/// # Example
+///
+/// ```
+/// # use or_tools::automaton::Automaton;
+/// // 0 is a day shift, 1 a night shift and 2 a day off: no more than 3
+/// // night shifts in a row, followed by at least 2 days off
+/// let automaton: Automaton = "([0 2] | 1{1,3} 2 2)*".parse().unwrap();
+/// assert!(automaton.accepts([0, 1, 1, 2, 2, 0]));
+/// assert!(!automaton.accepts([1, 1, 1, 1, 2, 2]));
+/// assert!(!automaton.accepts([0, 1, 2, 0]));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Automaton {
+    /// The starting state.
+    pub starting_state: i64,
+    /// The accepting states.
+    pub final_states: Vec<i64>,
+    /// The transitions, as `(tail, label, head)` triples: reading value
+    /// `label` in state `tail` moves the automaton to state `head`.
+    pub transitions: Vec<(i64, i64, i64)>,
+}
+
+impl Automaton {
+    /// Compiles a regex-like pattern into an automaton. See
+    /// [`Automaton`] for the syntax.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PatternError`] if the pattern is not valid, or too
+    /// large.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::automaton::Automaton;
+    /// let automaton = Automaton::from_pattern("1 0* 1").unwrap();
+    /// assert!(automaton.accepts([1, 1]));
+    /// assert!(automaton.accepts([1, 0, 0, 1]));
+    /// assert!(!automaton.accepts([1, 0]));
+    /// assert!(Automaton::from_pattern("1 (0").is_err());
+    /// ```
+    pub fn from_pattern(pattern: &str) -> Result<Self, PatternError> {
+        let mut parser = Parser {
+            chars: pattern.char_indices().peekable(),
+            len: pattern.len(),
+        };
+        let node = parser.parse_alternation()?;
+        if let Some((position, _)) = parser.chars.peek() {
+            return Err(PatternError::new(*position, "unexpected character"));
+        }
+        let mut nfa = Nfa::default();
+        let (start, accept) = nfa.compile(&node);
+        nfa.into_automaton(start, accept)
+    }
+
+    /// Returns true if the automaton accepts the given sequence of
+    /// values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::automaton::Automaton;
+    /// let automaton = Automaton::from_pattern("[1..=3]+").unwrap();
+    /// assert!(automaton.accepts([3, 1, 2]));
+    /// assert!(!automaton.accepts([]));
+    /// assert!(!automaton.accepts([4]));
+    /// ```
+    #[must_use]
+    pub fn accepts(&self, values: impl IntoIterator<Item = i64>) -> bool {
+        let transitions: BTreeMap<_, _> = self
+            .transitions
+            .iter()
+            .map(|&(tail, label, head)| ((tail, label), head))
+            .collect();
+        let mut state = self.starting_state;
+        for value in values {
+            match transitions.get(&(state, value)) {
+                Some(&head) => state = head,
+                None => return false,
+            }
+        }
+        self.final_states.contains(&state)
+    }
+}
+
+impl std::str::FromStr for Automaton {
+    type Err = PatternError;
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        Self::from_pattern(pattern)
+    }
+}
+
+/// Error returned when an [`Automaton`] pattern cannot be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PatternError {
+    position: usize,
+    message: &'static str,
+}
+
+impl PatternError {
+    fn new(position: usize, message: &'static str) -> Self {
+        Self { position, message }
+    }
+
+    /// Returns the byte offset in the pattern where the error occurred.
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl std::fmt::Display for PatternError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid automaton pattern at position {}: {}",
+            self.position, self.message
+        )
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+/// Maximal number of values in a set like `[0..=9]`.
+const MAX_SET_SIZE: u64 = 1 << 16;
+
+/// Maximal size of a pattern, once its repetitions are expanded, as
+/// computed by [`Node::size`].
+const MAX_PATTERN_SIZE: u64 = 1 << 20;
+
+/// Maximal number of states of the deterministic automaton, which can
+/// be exponential in the size of the pattern, e.g. for `[0 1]* 0 [0 1]{n}`.
+const MAX_STATES: usize = 1 << 14;
+
+/// Maximal number of transitions of the deterministic automaton.
+const MAX_TRANSITIONS: usize = 1 << 22;
+
+#[derive(Clone, Debug)]
+enum Node {
+    Values(BTreeSet<i64>),
+    Concat(Vec<Node>),
+    Alternation(Vec<Node>),
+    Repeat {
+        node: Box<Node>,
+        min: u32,
+        max: Option<u32>,
+    },
+}
+
+impl Node {
+    /// Returns the number of nodes and values of the pattern once its
+    /// repetitions are expanded, which bounds the size of the
+    /// non-deterministic automaton. Saturates at `u64::MAX`.
+    fn size(&self) -> u64 {
+        match self {
+            Node::Values(values) => 1 + values.len() as u64,
+            Node::Concat(nodes) | Node::Alternation(nodes) => nodes
+                .iter()
+                .fold(1, |size, node| size.saturating_add(node.size())),
+            Node::Repeat { node, min, max } => {
+                let copies = max.unwrap_or_else(|| min.saturating_add(1));
+                node.size()
+                    .saturating_mul(u64::from(copies))
+                    .saturating_add(1)
+            }
+        }
+    }
+
+    /// Returns the node, or an error at `position` if it is too large.
+    fn checked(self, position: usize) -> Result<Self, PatternError> {
+        if self.size() > MAX_PATTERN_SIZE {
+            return Err(PatternError::new(position, "pattern too large"));
+        }
+        Ok(self)
+    }
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+    len: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+    }
+
+    fn peek(&mut self) -> Option<(usize, char)> {
+        self.skip_whitespace();
+        self.chars.peek().copied()
+    }
+
+    fn position(&mut self) -> usize {
+        self.peek().map_or(self.len, |(position, _)| position)
+    }
+
+    fn expect(&mut self, expected: char, message: &'static str) -> Result<(), PatternError> {
+        match self.peek() {
+            Some((_, c)) if c == expected => {
+                self.chars.next();
+                Ok(())
+            }
+            _ => Err(PatternError::new(self.position(), message)),
+        }
+    }
+
+    fn parse_alternation(&mut self) -> Result<Node, PatternError> {
+        let position = self.position();
+        let mut alternatives = vec![self.parse_concat()?];
+        while let Some((_, '|')) = self.peek() {
+            self.chars.next();
+            alternatives.push(self.parse_concat()?);
+        }
+        if alternatives.len() == 1 {
+            Ok(alternatives.pop().unwrap_or(Node::Concat(Vec::new())))
+        } else {
+            Node::Alternation(alternatives).checked(position)
+        }
+    }
+
+    fn parse_concat(&mut self) -> Result<Node, PatternError> {
+        let position = self.position();
+        let mut nodes = Vec::new();
+        while let Some((_, c)) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            nodes.push(self.parse_repeat()?);
+        }
+        if nodes.len() == 1 {
+            Ok(nodes.pop().unwrap_or(Node::Concat(Vec::new())))
+        } else {
+            Node::Concat(nodes).checked(position)
+        }
+    }
+
+    fn parse_repeat(&mut self) -> Result<Node, PatternError> {
+        let mut node = self.parse_atom()?;
+        loop {
+            let position = self.position();
+            let (min, max) = match self.peek() {
+                Some((_, '*')) => (0, None),
+                Some((_, '+')) => (1, None),
+                Some((_, '?')) => (0, Some(1)),
+                Some((_, '{')) => {
+                    self.chars.next();
+                    let (min, max) = self.parse_bounds()?;
+                    node = Node::Repeat {
+                        node: Box::new(node),
+                        min,
+                        max,
+                    }
+                    .checked(position)?;
+                    continue;
+                }
+                _ => return Ok(node),
+            };
+            self.chars.next();
+            node = Node::Repeat {
+                node: Box::new(node),
+                min,
+                max,
+            }
+            .checked(position)?;
+        }
+    }
+
+    fn parse_bounds(&mut self) -> Result<(u32, Option<u32>), PatternError> {
+        let position = self.position();
+        let min = self.parse_count()?;
+        let max = match self.peek() {
+            Some((_, ',')) => {
+                self.chars.next();
+                match self.peek() {
+                    Some((_, '}')) => None,
+                    _ => Some(self.parse_count()?),
+                }
+            }
+            _ => Some(min),
+        };
+        self.expect('}', "expected '}'")?;
+        if max.is_some_and(|max| max < min) {
+            return Err(PatternError::new(position, "invalid repetition bounds"));
+        }
+        Ok((min, max))
+    }
+
+    fn parse_count(&mut self) -> Result<u32, PatternError> {
+        let position = self.position();
+        let value = self.parse_integer()?;
+        u32::try_from(value).map_err(|_| PatternError::new(position, "invalid repetition count"))
+    }
+
+    fn parse_integer(&mut self) -> Result<i64, PatternError> {
+        let position = self.position();
+        let mut digits = String::new();
+        if let Some((_, '-')) = self.chars.peek() {
+            self.chars.next();
+            digits.push('-');
+        }
+        while let Some((_, c)) = self.chars.next_if(|(_, c)| c.is_ascii_digit()) {
+            digits.push(c);
+        }
+        digits
+            .parse()
+            .map_err(|_| PatternError::new(position, "expected an integer"))
+    }
+
+    fn parse_atom(&mut self) -> Result<Node, PatternError> {
+        match self.peek() {
+            Some((_, '(')) => {
+                self.chars.next();
+                let node = self.parse_alternation()?;
+                self.expect(')', "expected ')'")?;
+                Ok(node)
+            }
+            Some((_, '[')) => {
+                self.chars.next();
+                let mut values = BTreeSet::new();
+                loop {
+                    if let Some((_, ']')) = self.peek() {
+                        self.chars.next();
+                        break;
+                    }
+                    let position = self.position();
+                    let first = self.parse_integer()?;
+                    if let Some((_, '.')) = self.chars.peek() {
+                        self.chars.next();
+                        self.expect('.', "expected '..='")?;
+                        self.expect('=', "expected '..='")?;
+                        let last = self.parse_integer()?;
+                        if last < first {
+                            return Err(PatternError::new(position, "invalid value range"));
+                        }
+                        let size = (values.len() as u64)
+                            .saturating_add(last.abs_diff(first))
+                            .saturating_add(1);
+                        if size > MAX_SET_SIZE {
+                            return Err(PatternError::new(position, "value set too large"));
+                        }
+                        values.extend(first..=last);
+                    } else {
+                        values.insert(first);
+                    }
+                }
+                Ok(Node::Values(values))
+            }
+            _ => Ok(Node::Values(BTreeSet::from([self.parse_integer()?]))),
+        }
+    }
+}
+
+#[derive(Default)]
+struct NfaState {
+    epsilon: Vec<usize>,
+    transitions: Vec<(i64, usize)>,
+}
+
+/// Non-deterministic automaton with epsilon transitions, built with
+/// Thompson's construction and then determinized.
+#[derive(Default)]
+struct Nfa {
+    states: Vec<NfaState>,
+}
+
+impl Nfa {
+    fn new_state(&mut self) -> usize {
+        self.states.push(NfaState::default());
+        self.states.len() - 1
+    }
+
+    fn add_epsilon(&mut self, from: usize, to: usize) {
+        if let Some(state) = self.states.get_mut(from) {
+            state.epsilon.push(to);
+        }
+    }
+
+    /// Compiles the node, and returns its start and accepting states.
+    fn compile(&mut self, node: &Node) -> (usize, usize) {
+        match node {
+            Node::Values(values) => {
+                let start = self.new_state();
+                let accept = self.new_state();
+                if let Some(state) = self.states.get_mut(start) {
+                    state
+                        .transitions
+                        .extend(values.iter().map(|&value| (value, accept)));
+                }
+                (start, accept)
+            }
+            Node::Concat(nodes) => {
+                let start = self.new_state();
+                let mut accept = start;
+                for node in nodes {
+                    let (node_start, node_accept) = self.compile(node);
+                    self.add_epsilon(accept, node_start);
+                    accept = node_accept;
+                }
+                (start, accept)
+            }
+            Node::Alternation(nodes) => {
+                let start = self.new_state();
+                let accept = self.new_state();
+                for node in nodes {
+                    let (node_start, node_accept) = self.compile(node);
+                    self.add_epsilon(start, node_start);
+                    self.add_epsilon(node_accept, accept);
+                }
+                (start, accept)
+            }
+            Node::Repeat { node, min, max } => {
+                let start = self.new_state();
+                let mut accept = start;
+                for _ in 0..*min {
+                    let (node_start, node_accept) = self.compile(node);
+                    self.add_epsilon(accept, node_start);
+                    accept = node_accept;
+                }
+                match max {
+                    None => {
+                        let (node_start, node_accept) = self.compile(node);
+                        let end = self.new_state();
+                        self.add_epsilon(accept, node_start);
+                        self.add_epsilon(accept, end);
+                        self.add_epsilon(node_accept, node_start);
+                        self.add_epsilon(node_accept, end);
+                        accept = end;
+                    }
+                    Some(max) => {
+                        let end = self.new_state();
+                        for _ in *min..*max {
+                            let (node_start, node_accept) = self.compile(node);
+                            self.add_epsilon(accept, node_start);
+                            self.add_epsilon(accept, end);
+                            accept = node_accept;
+                        }
+                        self.add_epsilon(accept, end);
+                        accept = end;
+                    }
+                }
+                (start, accept)
+            }
+        }
+    }
+
+    fn closure(&self, states: impl IntoIterator<Item = usize>) -> BTreeSet<usize> {
+        let mut closure = BTreeSet::new();
+        let mut stack: Vec<usize> = states.into_iter().collect();
+        while let Some(state) = stack.pop() {
+            if closure.insert(state)
+                && let Some(state) = self.states.get(state)
+            {
+                stack.extend(state.epsilon.iter().copied());
+            }
+        }
+        closure
+    }
+
+    /// Determinizes the automaton with the subset construction. Fails
+    /// with an error at the start of the pattern if the result has more
+    /// than [`MAX_STATES`] states or [`MAX_TRANSITIONS`] transitions.
+    fn into_automaton(self, start: usize, accept: usize) -> Result<Automaton, PatternError> {
+        let mut automaton = Automaton::default();
+        let start = self.closure([start]);
+        let mut ids = BTreeMap::from([(start.clone(), 0)]);
+        let mut queue = vec![start];
+        while let Some(subset) = queue.pop() {
+            let tail = ids.get(&subset).copied().unwrap_or_default();
+            if subset.contains(&accept) {
+                automaton.final_states.push(tail);
+            }
+            let mut moves: BTreeMap<i64, Vec<usize>> = BTreeMap::new();
+            for state in subset.iter().filter_map(|&state| self.states.get(state)) {
+                for &(label, head) in &state.transitions {
+                    moves.entry(label).or_default().push(head);
+                }
+            }
+            for (label, heads) in moves {
+                let heads = self.closure(heads);
+                let head = if let Some(&head) = ids.get(&heads) {
+                    head
+                } else {
+                    let head = i64::try_from(ids.len()).unwrap_or(i64::MAX);
+                    ids.insert(heads.clone(), head);
+                    queue.push(heads);
+                    head
+                };
+                if ids.len() > MAX_STATES || automaton.transitions.len() >= MAX_TRANSITIONS {
+                    return Err(PatternError::new(0, "pattern too large"));
+                }
+                automaton.transitions.push((tail, label, head));
+            }
+        }
+        automaton.final_states.sort_unstable();
+        automaton.transitions.sort_unstable();
+        Ok(automaton)
+    }
+}
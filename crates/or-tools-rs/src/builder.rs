@@ -1,12 +1,32 @@
-use crate::{ffi, proto};
+use crate::stats::ModelStats;
+use crate::{Domain, ffi, proto};
 use proto::constraint_proto::Constraint as CstEnum;
 use smallvec::SmallVec;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 #[allow(clippy::cast_precision_loss)]
 fn i64_to_f64(v: i64) -> f64 {
     v as f64
 }
 
+/// Iterates over the `(tail, head)` arcs of a graph constraint whose
+/// literal is true in the response, ignoring self-loops.
+fn selected_arcs<'a>(
+    tails: &'a [i32],
+    heads: &'a [i32],
+    literals: &'a [i32],
+    response: &'a proto::CpSolverResponse,
+) -> impl Iterator<Item = (i32, i32)> + 'a {
+    tails
+        .iter()
+        .zip(heads)
+        .zip(literals)
+        .filter(move |&((tail, head), &literal)| {
+            tail != head && BoolVar(literal).solution_value(response)
+        })
+        .map(|((&tail, &head), _)| (tail, head))
+}
+
 /// A builder for CP SAT.
 ///
 /// # Example
@@ -23,18 +43,105 @@ fn i64_to_f64(v: i64) -> f64 {
 /// assert!(x.solution_value(&response));
 /// assert!(y.solution_value(&response));
 /// ```
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct CpModelBuilder {
     proto: proto::CpModelProto,
+    /// The variables created by [`CpModelBuilder::new_constant`], by
+    /// value.
+    constants: HashMap<i64, IntVar>,
+    /// The constraints removed by [`CpModelBuilder::disable`], by
+    /// index.
+    disabled: HashMap<usize, proto::ConstraintProto>,
+    /// The constraints with an expression that overflowed, by index.
+    overflowed: HashSet<usize>,
+    /// Whether the expression of the objective overflowed.
+    objective_overflowed: bool,
+    /// Whether an expression of a search strategy overflowed.
+    strategy_overflowed: bool,
+    /// The position of each hinted variable in the solution hint,
+    /// rebuilt when it does not match the hint anymore.
+    hint_positions: HashMap<i32, usize>,
 }
 
 impl CpModelBuilder {
     /// Returns the corresponding [`proto::CpModelProto`].
+    ///
+    /// The expressions that overflowed are saturated in the proto,
+    /// which does not tell that they overflowed: check
+    /// [`CpModelBuilder::validate_cp_model`] before using it on its own.
     #[must_use]
     pub fn proto(&self) -> &proto::CpModelProto {
         &self.proto
     }
 
+    /// Creates a builder from an existing [`proto::CpModelProto`], for
+    /// example one that was deserialized, so that it can be extended
+    /// with the typed API. The handles of the existing variables,
+    /// intervals and constraints can be recovered with
+    /// [`CpModelBuilder::int_var`], [`CpModelBuilder::var_by_name`] and
+    /// the similar methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidModel`] if the model does not pass
+    /// [`ffi::validate_cp_model`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var_with_name([(0, 10)], "x");
+    /// model.maximize(x);
+    ///
+    /// let mut model = CpModelBuilder::from_proto(model.into_proto()).unwrap();
+    /// let x = model.var_by_name("x").unwrap();
+    /// model.add_le(x, 7);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(7, x.solution_value(&response));
+    /// ```
+    pub fn from_proto(proto: proto::CpModelProto) -> Result<Self, Error> {
+        let message = ffi::validate_cp_model(&proto);
+        if !message.is_empty() {
+            return Err(Error::InvalidModel(message));
+        }
+        let mut constants = HashMap::new();
+        for (index, var) in (0..).zip(&proto.variables) {
+            if let [value, max] = *var.domain
+                && value == max
+                && var.name.is_empty()
+            {
+                constants.entry(value).or_insert(IntVar(index));
+            }
+        }
+        Ok(Self {
+            proto,
+            constants,
+            ..Default::default()
+        })
+    }
+
+    /// Returns the underlying [`proto::CpModelProto`], consuming the
+    /// builder. The disabled constraints are left empty, and the
+    /// expressions that overflowed are saturated, see
+    /// [`CpModelBuilder::proto`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// model.new_bool_var_with_name("x");
+    /// let proto = model.into_proto();
+    /// assert_eq!("x", proto.variables[0].name);
+    /// ```
+    #[must_use]
+    pub fn into_proto(self) -> proto::CpModelProto {
+        self.proto
+    }
+
     /// Creates a new boolean variable, and returns the [`BoolVar`]
     /// identifier.
     ///
@@ -86,13 +193,15 @@ impl CpModelBuilder {
     /// Creates a new integer variable, and returns the [`IntVar`]
     /// identifier.
     ///
-    /// The domain of the variable is given. Bounds are included, so
-    /// `[(0, 2), (4, 8)]` means [0, 2]∪[4, 8].
+    /// The domain of the variable is given, either as a [`Domain`] or
+    /// as a list of intervals. Bounds are included, so `[(0, 2), (4,
+    /// 8)]` means [0, 2]∪[4, 8].
     ///
     /// # Example
     ///
     /// ```
     /// # use or_tools::builder::{CpModelBuilder, IntVar};
+    /// # use or_tools::Domain;
     /// # use or_tools::proto::CpSolverStatus;
     /// let mut model = CpModelBuilder::default();
     /// let x = model.new_int_var([(0, 2), (4, 8)]);
@@ -100,8 +209,11 @@ impl CpModelBuilder {
     /// assert_eq!(response.status(), CpSolverStatus::Optimal);
     /// let x_val = x.solution_value(&response);
     /// assert!(0 <= x_val && x_val <= 2 || 4 <= x_val && 8 <= x_val);
+    ///
+    /// let y = model.new_int_var(Domain::from_values([1, 3, 5]));
+    /// assert_eq!(Domain::from_values([1, 3, 5]), model.var_domain(y));
     /// ```
-    pub fn new_int_var(&mut self, domain: impl IntoIterator<Item = (i64, i64)>) -> IntVar {
+    pub fn new_int_var(&mut self, domain: impl Into<Domain>) -> IntVar {
         self.new_int_var_with_name(domain, "")
     }
 
@@ -119,18 +231,91 @@ impl CpModelBuilder {
     /// ```
     pub fn new_int_var_with_name(
         &mut self,
-        domain: impl IntoIterator<Item = (i64, i64)>,
+        domain: impl Into<Domain>,
         name: impl Into<String>,
     ) -> IntVar {
         #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
         let index = self.proto.variables.len() as i32;
         self.proto.variables.push(proto::IntegerVariableProto {
             name: name.into(),
-            domain: domain.into_iter().flat_map(|(b, e)| [b, e]).collect(),
+            domain: domain.into().to_flat_intervals(),
         });
         IntVar(index)
     }
 
+    /// Returns a variable fixed to the given value.
+    ///
+    /// The variables are cached, so asking twice for the same value
+    /// returns the same variable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let three = model.new_constant(3);
+    /// assert_eq!(three, model.new_constant(3));
+    /// model.add_ge(x, three);
+    /// model.minimize(x);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(3, x.solution_value(&response));
+    /// ```
+    pub fn new_constant(&mut self, value: i64) -> IntVar {
+        if let Some(&var) = self.constants.get(&value) {
+            return var;
+        }
+        let var = self.new_int_var([(value, value)]);
+        self.constants.insert(value, var);
+        var
+    }
+
+    /// Returns a literal that is always true.
+    ///
+    /// The literal is created once, and shares its variable with
+    /// `new_constant(1)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, IntVar};
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let t = model.true_literal();
+    /// assert_eq!(t, model.true_literal());
+    /// assert_eq!(IntVar::from(t), model.new_constant(1));
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(t.solution_value(&response));
+    /// ```
+    pub fn true_literal(&mut self) -> BoolVar {
+        BoolVar(self.new_constant(1).0)
+    }
+
+    /// Returns a literal that is always false, which is the negation
+    /// of [`CpModelBuilder::true_literal`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let f = model.false_literal();
+    /// assert_eq!(!f, model.true_literal());
+    /// let x = model.new_bool_var();
+    /// model.add_or([x, f]);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(x.solution_value(&response));
+    /// assert!(!f.solution_value(&response));
+    /// ```
+    pub fn false_literal(&mut self) -> BoolVar {
+        !self.true_literal()
+    }
+
     /// Returns the name of a variable, empty string if not set.
     ///
     /// # Example
@@ -170,52 +355,102 @@ impl CpModelBuilder {
         }
     }
 
-    /// Returns the name of a constraint, empty string if not set.
+    /// Returns the domain of a variable.
     ///
     /// # Example
     ///
     /// ```
     /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::Domain;
     /// let mut model = CpModelBuilder::default();
     /// let x = model.new_bool_var();
-    /// let constraint = model.add_or([x]);
-    /// assert_eq!("", model.constraint_name(constraint));
-    /// model.set_constraint_name(constraint, "or");
-    /// assert_eq!("or", model.constraint_name(constraint));
+    /// assert_eq!(Domain::new(0, 1), model.var_domain(x));
+    /// let y = model.new_int_var([(4, 8), (0, 2)]);
+    /// assert_eq!(&[(0, 2), (4, 8)], model.var_domain(y).intervals());
     /// ```
     #[must_use]
-    pub fn constraint_name(&self, constraint: Constraint) -> &str {
-        #[allow(clippy::indexing_slicing)]
-        &self.proto.constraints[constraint.0].name
+    pub fn var_domain(&self, var: impl Into<IntVar>) -> Domain {
+        usize::try_from(var.into().0)
+            .ok()
+            .and_then(|var| self.proto.variables.get(var))
+            .map(|var| Domain::from_flat_intervals(&var.domain))
+            .unwrap_or_default()
     }
 
-    /// Sets the name of a constraint.
+    /// Restricts the domain of a variable to the values that are also
+    /// in `domain`, by modifying the domain of the variable in the
+    /// model rather than by adding a constraint. The domain of a
+    /// negated literal `!b` restricts `b` to the opposite values.
+    ///
+    /// If no value is left, the domain is kept as it is and a
+    /// constraint that can never be satisfied is added instead, so that
+    /// solving the model returns
+    /// [`proto::CpSolverStatus::Infeasible`].
     ///
     /// # Example
     ///
     /// ```
     /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::Domain;
+    /// # use or_tools::proto::CpSolverStatus;
     /// let mut model = CpModelBuilder::default();
-    /// let x = model.new_bool_var();
-    /// let constraint = model.add_or([x]);
-    /// model.set_constraint_name(constraint, "or");
-    /// assert_eq!("or", model.constraint_name(constraint));
+    /// let x = model.new_int_var([(0, 10)]);
+    /// model.restrict_domain(x, [(2, 4), (8, 12)]);
+    /// assert_eq!(Domain::from([(2, 4), (8, 10)]), model.var_domain(x));
+    /// let b = model.new_bool_var();
+    /// model.restrict_domain(!b, [(0, 0)]);
+    /// assert_eq!(Domain::new(1, 1), model.var_domain(b));
+    ///
+    /// model.restrict_domain(x, [(5, 7)]);
+    /// assert_eq!(Domain::from([(2, 4), (8, 10)]), model.var_domain(x));
+    /// assert_eq!(model.solve().status(), CpSolverStatus::Infeasible);
     /// ```
-    pub fn set_constraint_name(&mut self, constraint: Constraint, name: &str) {
+    pub fn restrict_domain(&mut self, var: impl Into<IntVar>, domain: impl Into<Domain>) {
+        let (var, domain) = match var.into() {
+            var if var.0 < 0 => (
+                var.not(),
+                Domain::new(1, 1).addition(&domain.into().negation()),
+            ),
+            var => (var, domain.into()),
+        };
+        let domain = self.var_domain(var).intersection(&domain);
+        if domain.is_empty() {
+            // an empty domain would make the model invalid
+            self.add_or([]);
+            return;
+        }
+        #[allow(clippy::cast_sign_loss)]
+        let idx = var.0 as usize;
         #[allow(clippy::indexing_slicing)]
         {
-            self.proto.constraints[constraint.0].name = name.into();
+            self.proto.variables[idx].domain = domain.to_flat_intervals();
         }
     }
 
-    /// Sets enforcement literals on a constraint. The constraint will only
-    /// be enforced when ALL the given literals are true.
+    /// Fixes a variable to the given value, by restricting its domain
+    /// with [`CpModelBuilder::restrict_domain`].
     ///
-    /// This is also called "half-reification". When the enforcement literals
-    /// are false, the constraint is simply ignored.
+    /// # Example
     ///
-    /// Note: Only `bool_or`, `bool_and`, and linear constraints fully support
-    /// enforcement literals.
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(0, 10)]);
+    /// model.add_eq(x + y, 12);
+    /// model.fix(x, 9);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(3, y.solution_value(&response));
+    /// ```
+    pub fn fix(&mut self, var: impl Into<IntVar>, value: i64) {
+        self.restrict_domain(var, Domain::new(value, value));
+    }
+
+    /// Fixes the given variables to their value in the solution of
+    /// `response`, which must come from a model with the same
+    /// variables.
     ///
     /// # Example
     ///
@@ -223,35 +458,33 @@ impl CpModelBuilder {
     /// # use or_tools::builder::CpModelBuilder;
     /// # use or_tools::proto::CpSolverStatus;
     /// let mut model = CpModelBuilder::default();
-    /// let x = model.new_bool_var();
-    /// let y = model.new_bool_var();
-    /// let z = model.new_bool_var();
-    /// // z must be true, but only if x is true
-    /// let cst = model.add_and([z]);
-    /// model.only_enforce_if(cst, [x]);
-    /// // force x to be false, so z can be anything
-    /// model.add_and([!x, !y]);
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(0, 10)]);
+    /// model.add_le(x + y, 12);
+    /// model.maximize(x);
     /// let response = model.solve();
     /// assert_eq!(response.status(), CpSolverStatus::Optimal);
-    /// assert!(!x.solution_value(&response));
-    /// // z is unconstrained since x is false
+    ///
+    /// model.fix_from_response([x], &response);
+    /// model.maximize(y);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!((10, 2), (x.solution_value(&response), y.solution_value(&response)));
     /// ```
-    pub fn only_enforce_if(
+    pub fn fix_from_response(
         &mut self,
-        constraint: Constraint,
-        literals: impl IntoIterator<Item = BoolVar>,
+        vars: impl IntoIterator<Item = impl Into<IntVar>>,
+        response: &proto::CpSolverResponse,
     ) {
-        #[allow(clippy::indexing_slicing)]
-        {
-            self.proto.constraints[constraint.0]
-                .enforcement_literal
-                .extend(literals.into_iter().map(|v| v.0));
+        for var in vars {
+            let var = var.into();
+            self.fix(var, var.solution_value(response));
         }
     }
 
-    /// Adds an implication constraint: if `a` is true, then `b` must be true.
-    ///
-    /// This is equivalent to `add_or([!a, b])`.
+    /// Returns a copy of the model modified by `scenario`, leaving this
+    /// model untouched. The handles of this model are valid in the
+    /// copy.
     ///
     /// # Example
     ///
@@ -259,154 +492,821 @@ impl CpModelBuilder {
     /// # use or_tools::builder::CpModelBuilder;
     /// # use or_tools::proto::CpSolverStatus;
     /// let mut model = CpModelBuilder::default();
-    /// let a = model.new_bool_var();
-    /// let b = model.new_bool_var();
-    /// model.add_implication(a, b); // a => b
-    /// model.add_and([a]); // force a to be true
-    /// let response = model.solve();
-    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
-    /// assert!(a.solution_value(&response));
-    /// assert!(b.solution_value(&response)); // b must be true since a is true
+    /// let x = model.new_int_var([(0, 10)]);
+    /// model.maximize(x);
+    /// let capped = model.clone_with(|m| m.restrict_domain(x, [(0, 5)]));
+    /// assert_eq!(5, x.solution_value(&capped.solve()));
+    /// assert_eq!(10, x.solution_value(&model.solve()));
     /// ```
-    pub fn add_implication(&mut self, a: BoolVar, b: BoolVar) -> Constraint {
-        self.add_or([!a, b])
+    #[must_use]
+    pub fn clone_with(&self, scenario: impl FnOnce(&mut Self)) -> Self {
+        let mut model = self.clone();
+        scenario(&mut model);
+        model
     }
 
-    /// Adds a boolean OR constraint on a list of [`BoolVar`].
+    /// Returns the name of a constraint, empty string if not set.
     ///
     /// # Example
     ///
     /// ```
     /// # use or_tools::builder::CpModelBuilder;
-    /// # use or_tools::proto::CpSolverStatus;
     /// let mut model = CpModelBuilder::default();
     /// let x = model.new_bool_var();
-    /// let y = model.new_bool_var();
-    /// model.add_or([x, y]);
-    /// let response = model.solve();
-    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
-    /// assert!(x.solution_value(&response) || y.solution_value(&response));
+    /// let constraint = model.add_or([x]);
+    /// assert_eq!("", model.constraint_name(constraint));
+    /// model.set_constraint_name(constraint, "or");
+    /// assert_eq!("or", model.constraint_name(constraint));
     /// ```
-    pub fn add_or(&mut self, vars: impl IntoIterator<Item = BoolVar>) -> Constraint {
-        self.add_cst(CstEnum::BoolOr(proto::BoolArgumentProto {
-            literals: vars.into_iter().map(|v| v.0).collect(),
-        }))
+    #[must_use]
+    pub fn constraint_name(&self, constraint: impl Into<Constraint>) -> &str {
+        #[allow(clippy::indexing_slicing)]
+        &self.proto.constraints[constraint.into().0].name
     }
 
-    /// Adds a boolean AND constraint on a list of [`BoolVar`].
+    /// Sets the name of a constraint.
     ///
     /// # Example
     ///
     /// ```
     /// # use or_tools::builder::CpModelBuilder;
-    /// # use or_tools::proto::CpSolverStatus;
     /// let mut model = CpModelBuilder::default();
     /// let x = model.new_bool_var();
-    /// let y = model.new_bool_var();
-    /// model.add_and([x, y]);
-    /// let response = model.solve();
-    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
-    /// assert!(x.solution_value(&response));
-    /// assert!(y.solution_value(&response));
+    /// let constraint = model.add_or([x]);
+    /// model.set_constraint_name(constraint, "or");
+    /// assert_eq!("or", model.constraint_name(constraint));
     /// ```
-    pub fn add_and(&mut self, vars: impl IntoIterator<Item = BoolVar>) -> Constraint {
-        self.add_cst(CstEnum::BoolAnd(proto::BoolArgumentProto {
-            literals: vars.into_iter().map(|v| v.0).collect(),
-        }))
+    pub fn set_constraint_name(&mut self, constraint: impl Into<Constraint>, name: &str) {
+        #[allow(clippy::indexing_slicing)]
+        {
+            self.proto.constraints[constraint.into().0].name = name.into();
+        }
     }
 
-    /// Adds a boolean "at most one" constraint on a list of [`BoolVar`].
+    /// Iterates over the variables of the model, in creation order.
     ///
     /// # Example
     ///
     /// ```
+    /// # use or_tools::Domain;
     /// # use or_tools::builder::CpModelBuilder;
-    /// # use or_tools::proto::CpSolverStatus;
     /// let mut model = CpModelBuilder::default();
-    /// let vars: Vec<_> = (0..10).map(|_| model.new_bool_var()).collect();
-    /// model.add_at_most_one(vars.iter().copied());
-    /// let response = model.solve();
-    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
-    /// assert!(
-    ///     vars.iter()
-    ///         .map(|v| v.solution_value(&response) as u32)
-    ///         .sum::<u32>()
-    ///         <= 1
-    /// );
+    /// let x = model.new_bool_var_with_name("x");
+    /// let y = model.new_int_var([(0, 2), (5, 6)]);
+    /// let vars: Vec<_> = model.variables().collect();
+    /// assert_eq!(2, vars.len());
+    /// assert_eq!((x.into(), "x"), (vars[0].var, vars[0].name));
+    /// assert_eq!((y, ""), (vars[1].var, vars[1].name));
+    /// assert_eq!(Domain::from([(0, 2), (5, 6)]), vars[1].domain);
     /// ```
-    pub fn add_at_most_one(&mut self, vars: impl IntoIterator<Item = BoolVar>) -> Constraint {
-        self.add_cst(CstEnum::AtMostOne(proto::BoolArgumentProto {
-            literals: vars.into_iter().map(|v| v.0).collect(),
-        }))
+    pub fn variables(&self) -> impl Iterator<Item = VarView<'_>> {
+        (0..)
+            .zip(&self.proto.variables)
+            .map(|(index, var)| VarView {
+                var: IntVar(index),
+                name: &var.name,
+                domain: Domain::from_flat_intervals(&var.domain),
+            })
     }
 
-    /// Adds a boolean "exactly one" constraint on a list of [`BoolVar`].
+    /// Iterates over the constraints of the model, in creation order.
+    /// Constraints disabled by [`CpModelBuilder::disable`] are included,
+    /// see [`ConstraintView::enabled`].
     ///
     /// # Example
     ///
     /// ```
-    /// # use or_tools::builder::CpModelBuilder;
-    /// # use or_tools::proto::CpSolverStatus;
+    /// # use or_tools::builder::{ConstraintKind, CpModelBuilder};
     /// let mut model = CpModelBuilder::default();
-    /// let vars: Vec<_> = (0..10).map(|_| model.new_bool_var()).collect();
-    /// model.add_exactly_one(vars.iter().copied());
-    /// let response = model.solve();
-    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
-    /// assert!(
-    ///     vars.iter()
-    ///         .map(|v| v.solution_value(&response) as u32)
-    ///         .sum::<u32>()
-    ///         == 1
-    /// );
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(0, 10)]);
+    /// let b = model.new_bool_var();
+    /// let cst = model.add_le(x, y);
+    /// model.only_enforce_if(cst, [!b]);
+    /// model.set_constraint_name(cst, "x before y");
+    ///
+    /// let views: Vec<_> = model.constraints().collect();
+    /// assert_eq!(1, views.len());
+    /// assert_eq!(cst, views[0].constraint);
+    /// assert_eq!("x before y", views[0].name);
+    /// assert_eq!(ConstraintKind::Linear, views[0].kind);
+    /// assert_eq!(vec![!b], views[0].enforcement_literals);
+    /// assert_eq!(vec![x, y], views[0].vars);
+    /// assert!(views[0].enabled);
     /// ```
-    pub fn add_exactly_one(&mut self, vars: impl IntoIterator<Item = BoolVar>) -> Constraint {
-        self.add_cst(CstEnum::ExactlyOne(proto::BoolArgumentProto {
-            literals: vars.into_iter().map(|v| v.0).collect(),
-        }))
+    pub fn constraints(&self) -> impl Iterator<Item = ConstraintView<'_>> {
+        self.proto
+            .constraints
+            .iter()
+            .enumerate()
+            .map(|(index, placeholder)| {
+                // a disabled constraint is seen as it will be once enabled
+                let disabled = self.disabled.get(&index);
+                let cst = disabled.unwrap_or(placeholder);
+                ConstraintView {
+                    constraint: Constraint(index),
+                    name: &placeholder.name,
+                    kind: ConstraintKind::of(cst),
+                    enabled: disabled.is_none(),
+                    enforcement_literals: cst
+                        .enforcement_literal
+                        .iter()
+                        .chain(disabled.map_or(&[][..], |_| &placeholder.enforcement_literal))
+                        .map(|&l| BoolVar(l))
+                        .collect(),
+                    vars: cst
+                        .constraint
+                        .as_ref()
+                        .map(constraint_vars)
+                        .unwrap_or_default(),
+                    proto: cst,
+                }
+            })
     }
 
-    /// Adds a boolean XOR constraint on a list of [`BoolVar`].
+    /// Returns the integer variable at the given index, if the model
+    /// has that many variables.
     ///
     /// # Example
     ///
     /// ```
     /// # use or_tools::builder::CpModelBuilder;
-    /// # use or_tools::proto::CpSolverStatus;
     /// let mut model = CpModelBuilder::default();
-    /// let vars: Vec<_> = (0..10).map(|_| model.new_bool_var()).collect();
-    /// model.add_xor(vars.iter().copied());
-    /// let response = model.solve();
-    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
-    /// assert!(
-    ///     vars.iter()
-    ///         .map(|v| v.solution_value(&response) as u32)
-    ///         .sum::<u32>()
-    ///         % 2
-    ///         == 1
-    /// );
+    /// let x = model.new_int_var([(0, 10)]);
+    /// assert_eq!(Some(x), model.int_var(0));
+    /// assert_eq!(None, model.int_var(1));
     /// ```
-    pub fn add_xor(&mut self, vars: impl IntoIterator<Item = BoolVar>) -> Constraint {
-        self.add_cst(CstEnum::BoolXor(proto::BoolArgumentProto {
-            literals: vars.into_iter().map(|v| v.0).collect(),
-        }))
+    #[must_use]
+    pub fn int_var(&self, index: usize) -> Option<IntVar> {
+        let var = i32::try_from(index).ok()?;
+        (index < self.proto.variables.len()).then_some(IntVar(var))
     }
 
-    /// Adds a "all different" constraint on a list of [`BoolVar`].
+    /// Returns the variable at the given index as a boolean variable,
+    /// if it exists and its domain is included in `[0, 1]`.
     ///
     /// # Example
     ///
     /// ```
     /// # use or_tools::builder::CpModelBuilder;
-    /// # use or_tools::proto::CpSolverStatus;
     /// let mut model = CpModelBuilder::default();
-    /// let x = model.new_int_var([(0, 2)]);
-    /// let y = model.new_int_var([(0, 2)]);
-    /// let z = model.new_int_var([(0, 2)]);
-    /// model.add_all_different([x, y, z]);
-    /// let response = model.solve();
-    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
-    /// let x_val = x.solution_value(&response);
-    /// let y_val = y.solution_value(&response);
+    /// let x = model.new_bool_var();
+    /// model.new_int_var([(0, 10)]);
+    /// assert_eq!(Some(x), model.bool_var(0));
+    /// assert_eq!(None, model.bool_var(1));
+    /// assert_eq!(None, model.bool_var(2));
+    /// ```
+    #[must_use]
+    pub fn bool_var(&self, index: usize) -> Option<BoolVar> {
+        let var = self.int_var(index)?;
+        let domain = self.var_domain(var);
+        (!domain.is_empty() && domain.min() >= Some(0) && domain.max() <= Some(1))
+            .then_some(BoolVar(var.0))
+    }
+
+    /// Returns the interval variable defined by the constraint at the
+    /// given index, if this constraint is an interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// let start = model.new_int_var([(0, 10)]);
+    /// let interval = model.new_fixed_size_interval_var(start, 3);
+    /// model.add_no_overlap(&[interval]);
+    /// assert_eq!(Some(interval), model.interval_var(0));
+    /// assert_eq!(None, model.interval_var(1));
+    /// ```
+    #[must_use]
+    pub fn interval_var(&self, index: usize) -> Option<IntervalVar> {
+        let interval = i32::try_from(index).ok()?;
+        matches!(
+            self.constraint_body(Constraint(index))?,
+            CstEnum::Interval(_)
+        )
+        .then_some(IntervalVar(interval))
+    }
+
+    /// Returns the constraint at the given index, if the model has
+    /// that many constraints.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_bool_var();
+    /// let constraint = model.add_or([x]);
+    /// assert_eq!(Some(constraint), model.constraint(0));
+    /// assert_eq!(None, model.constraint(1));
+    /// ```
+    #[must_use]
+    pub fn constraint(&self, index: usize) -> Option<Constraint> {
+        (index < self.proto.constraints.len()).then_some(Constraint(index))
+    }
+
+    /// Returns the first variable with the given name, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var_with_name([(0, 10)], "x");
+    /// assert_eq!(Some(x), model.var_by_name("x"));
+    /// assert_eq!(None, model.var_by_name("y"));
+    /// ```
+    #[must_use]
+    pub fn var_by_name(&self, name: &str) -> Option<IntVar> {
+        self.variables()
+            .find(|view| view.name == name)
+            .map(|view| view.var)
+    }
+
+    /// Returns the first constraint with the given name, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_bool_var();
+    /// let constraint = model.add_or([x]);
+    /// model.set_constraint_name(constraint, "or");
+    /// assert_eq!(Some(constraint), model.constraint_by_name("or"));
+    /// assert_eq!(None, model.constraint_by_name("and"));
+    /// ```
+    #[must_use]
+    pub fn constraint_by_name(&self, name: &str) -> Option<Constraint> {
+        self.proto
+            .constraints
+            .iter()
+            .position(|cst| cst.name == name)
+            .map(Constraint)
+    }
+
+    /// Returns the first variable with the given name as a boolean
+    /// variable, if it exists and its domain is included in `[0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// let b = model.new_bool_var_with_name("b");
+    /// model.new_int_var_with_name([(0, 10)], "x");
+    /// assert_eq!(Some(b), model.bool_var_by_name("b"));
+    /// assert_eq!(None, model.bool_var_by_name("x"));
+    /// ```
+    #[must_use]
+    pub fn bool_var_by_name(&self, name: &str) -> Option<BoolVar> {
+        let var = self.var_by_name(name)?;
+        self.bool_var(usize::try_from(var.0).ok()?)
+    }
+
+    /// Returns the first interval variable with the given name, if
+    /// any. The name of an interval is the name of its constraint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// let start = model.new_int_var([(0, 10)]);
+    /// let interval = model.new_fixed_size_interval_var(start, 3);
+    /// model.set_constraint_name(interval.constraint(), "task");
+    /// assert_eq!(Some(interval), model.interval_var_by_name("task"));
+    /// assert_eq!(None, model.interval_var_by_name("other task"));
+    /// ```
+    #[must_use]
+    pub fn interval_var_by_name(&self, name: &str) -> Option<IntervalVar> {
+        self.interval_var(self.constraint_by_name(name)?.0)
+    }
+
+    /// Returns the number of constraints of each kind present in the
+    /// model. Constraints disabled by [`CpModelBuilder::disable`] are
+    /// not counted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{ConstraintKind, CpModelBuilder};
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_bool_var();
+    /// let y = model.new_bool_var();
+    /// model.add_or([x, y]);
+    /// model.add_or([!x, !y]);
+    /// model.add_eq(x, y);
+    /// let counts = model.constraint_counts();
+    /// assert_eq!(Some(&2), counts.get(&ConstraintKind::BoolOr));
+    /// assert_eq!(Some(&1), counts.get(&ConstraintKind::Linear));
+    /// assert_eq!(None, counts.get(&ConstraintKind::BoolAnd));
+    /// ```
+    #[must_use]
+    pub fn constraint_counts(&self) -> BTreeMap<ConstraintKind, usize> {
+        let mut counts = BTreeMap::new();
+        for view in self.constraints().filter(|view| view.enabled) {
+            *counts.entry(view.kind).or_default() += 1;
+        }
+        counts
+    }
+
+    /// Sets enforcement literals on a constraint. The constraint will only
+    /// be enforced when ALL the given literals are true.
+    ///
+    /// This is also called "half-reification". When the enforcement literals
+    /// are false, the constraint is simply ignored.
+    ///
+    /// Note: Only `bool_or`, `bool_and`, and linear constraints fully support
+    /// enforcement literals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_bool_var();
+    /// let y = model.new_bool_var();
+    /// let z = model.new_bool_var();
+    /// // z must be true, but only if x is true
+    /// let cst = model.add_and([z]);
+    /// model.only_enforce_if(cst, [x]);
+    /// // force x to be false, so z can be anything
+    /// model.add_and([!x, !y]);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(!x.solution_value(&response));
+    /// // z is unconstrained since x is false
+    /// ```
+    pub fn only_enforce_if(
+        &mut self,
+        constraint: impl Into<Constraint>,
+        literals: impl IntoIterator<Item = BoolVar>,
+    ) {
+        #[allow(clippy::indexing_slicing)]
+        {
+            self.proto.constraints[constraint.into().0]
+                .enforcement_literal
+                .extend(literals.into_iter().map(|v| v.0));
+        }
+    }
+
+    /// Disables a constraint, so that it is ignored by the solver until
+    /// it is enabled again with [`CpModelBuilder::enable`]. Disabling a
+    /// disabled constraint does nothing.
+    ///
+    /// The constraint is replaced by an empty constraint with the same
+    /// name, so that the indices of the other constraints, and thus
+    /// their handles, are unchanged. Demands, events and arcs added to
+    /// a disabled constraint are kept for when it is enabled again.
+    /// Intervals must not be disabled while other constraints refer to
+    /// them, as the model would become invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let cap = model.add_le(x, 3);
+    /// model.maximize(x);
+    /// model.disable(cap);
+    /// assert!(!model.is_enabled(cap));
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(10, x.solution_value(&response));
+    /// ```
+    pub fn disable(&mut self, constraint: impl Into<Constraint>) {
+        let constraint = constraint.into();
+        if !self.is_enabled(constraint) {
+            return;
+        }
+        let Some(cst) = self.proto.constraints.get_mut(constraint.0) else {
+            return;
+        };
+        let placeholder = proto::ConstraintProto {
+            name: cst.name.clone(),
+            ..Default::default()
+        };
+        self.disabled
+            .insert(constraint.0, std::mem::replace(cst, placeholder));
+    }
+
+    /// Enables a constraint disabled by [`CpModelBuilder::disable`].
+    /// Enabling an enabled constraint does nothing. The name and the
+    /// enforcement literals set while the constraint was disabled are
+    /// kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let cap = model.add_le(x, 3);
+    /// model.maximize(x);
+    /// model.disable(cap);
+    /// model.enable(cap);
+    /// assert!(model.is_enabled(cap));
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(3, x.solution_value(&response));
+    /// ```
+    pub fn enable(&mut self, constraint: impl Into<Constraint>) {
+        let constraint = constraint.into();
+        let Some(mut cst) = self.disabled.remove(&constraint.0) else {
+            return;
+        };
+        #[allow(clippy::indexing_slicing)]
+        let placeholder = &mut self.proto.constraints[constraint.0];
+        cst.name = std::mem::take(&mut placeholder.name);
+        cst.enforcement_literal
+            .append(&mut placeholder.enforcement_literal);
+        *placeholder = cst;
+    }
+
+    /// Returns the proto of the given constraint, looking into the
+    /// disabled constraints first, so that what is added to a disabled
+    /// constraint is kept when it is enabled again.
+    fn constraint_proto(&self, constraint: Constraint) -> Option<&proto::ConstraintProto> {
+        self.disabled
+            .get(&constraint.0)
+            .or_else(|| self.proto.constraints.get(constraint.0))
+    }
+
+    /// Returns the constraint of the given handle, see
+    /// [`CpModelBuilder::constraint_proto`].
+    fn constraint_body(&self, constraint: Constraint) -> Option<&CstEnum> {
+        self.constraint_proto(constraint)?.constraint.as_ref()
+    }
+
+    /// Mutable version of [`CpModelBuilder::constraint_body`].
+    fn constraint_body_mut(&mut self, constraint: Constraint) -> Option<&mut CstEnum> {
+        match self.disabled.get_mut(&constraint.0) {
+            Some(cst) => cst.constraint.as_mut(),
+            None => self
+                .proto
+                .constraints
+                .get_mut(constraint.0)?
+                .constraint
+                .as_mut(),
+        }
+    }
+
+    /// Returns true if the constraint is not disabled by
+    /// [`CpModelBuilder::disable`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_bool_var();
+    /// let constraint = model.add_or([x]);
+    /// assert!(model.is_enabled(constraint));
+    /// model.disable(constraint);
+    /// assert!(!model.is_enabled(constraint));
+    /// ```
+    #[must_use]
+    pub fn is_enabled(&self, constraint: impl Into<Constraint>) -> bool {
+        !self.disabled.contains_key(&constraint.into().0)
+    }
+
+    /// Adds an implication constraint: if `a` is true, then `b` must be true.
+    ///
+    /// This is equivalent to `add_or([!a, b])`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let a = model.new_bool_var();
+    /// let b = model.new_bool_var();
+    /// model.add_implication(a, b); // a => b
+    /// model.add_and([a]); // force a to be true
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(a.solution_value(&response));
+    /// assert!(b.solution_value(&response)); // b must be true since a is true
+    /// ```
+    pub fn add_implication(&mut self, a: BoolVar, b: BoolVar) -> Constraint {
+        self.add_or([!a, b])
+    }
+
+    /// Adds a boolean OR constraint on a list of [`BoolVar`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_bool_var();
+    /// let y = model.new_bool_var();
+    /// model.add_or([x, y]);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(x.solution_value(&response) || y.solution_value(&response));
+    /// ```
+    pub fn add_or(&mut self, vars: impl IntoIterator<Item = BoolVar>) -> Constraint {
+        self.add_cst(CstEnum::BoolOr(proto::BoolArgumentProto {
+            literals: vars.into_iter().map(|v| v.0).collect(),
+        }))
+    }
+
+    /// Adds a boolean AND constraint on a list of [`BoolVar`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_bool_var();
+    /// let y = model.new_bool_var();
+    /// model.add_and([x, y]);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(x.solution_value(&response));
+    /// assert!(y.solution_value(&response));
+    /// ```
+    pub fn add_and(&mut self, vars: impl IntoIterator<Item = BoolVar>) -> Constraint {
+        self.add_cst(CstEnum::BoolAnd(proto::BoolArgumentProto {
+            literals: vars.into_iter().map(|v| v.0).collect(),
+        }))
+    }
+
+    /// Adds a boolean "at most one" constraint on a list of [`BoolVar`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let vars: Vec<_> = (0..10).map(|_| model.new_bool_var()).collect();
+    /// model.add_at_most_one(vars.iter().copied());
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(
+    ///     vars.iter()
+    ///         .map(|v| v.solution_value(&response) as u32)
+    ///         .sum::<u32>()
+    ///         <= 1
+    /// );
+    /// ```
+    pub fn add_at_most_one(&mut self, vars: impl IntoIterator<Item = BoolVar>) -> Constraint {
+        self.add_cst(CstEnum::AtMostOne(proto::BoolArgumentProto {
+            literals: vars.into_iter().map(|v| v.0).collect(),
+        }))
+    }
+
+    /// Adds a boolean "exactly one" constraint on a list of [`BoolVar`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let vars: Vec<_> = (0..10).map(|_| model.new_bool_var()).collect();
+    /// model.add_exactly_one(vars.iter().copied());
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(
+    ///     vars.iter()
+    ///         .map(|v| v.solution_value(&response) as u32)
+    ///         .sum::<u32>()
+    ///         == 1
+    /// );
+    /// ```
+    pub fn add_exactly_one(&mut self, vars: impl IntoIterator<Item = BoolVar>) -> Constraint {
+        self.add_cst(CstEnum::ExactlyOne(proto::BoolArgumentProto {
+            literals: vars.into_iter().map(|v| v.0).collect(),
+        }))
+    }
+
+    /// Adds a boolean XOR constraint on a list of [`BoolVar`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let vars: Vec<_> = (0..10).map(|_| model.new_bool_var()).collect();
+    /// model.add_xor(vars.iter().copied());
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(
+    ///     vars.iter()
+    ///         .map(|v| v.solution_value(&response) as u32)
+    ///         .sum::<u32>()
+    ///         % 2
+    ///         == 1
+    /// );
+    /// ```
+    pub fn add_xor(&mut self, vars: impl IntoIterator<Item = BoolVar>) -> Constraint {
+        self.add_cst(CstEnum::BoolXor(proto::BoolArgumentProto {
+            literals: vars.into_iter().map(|v| v.0).collect(),
+        }))
+    }
+
+    /// Adds a constraint forcing a Boolean formula to be true.
+    ///
+    /// The formula is compiled into `bool_or` and `bool_and`
+    /// constraints. Auxiliary literals are only created for the
+    /// sub-formulas that are not literals, and they only imply their
+    /// sub-formula when this is enough.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let (a, b, c) = (model.new_bool_var(), model.new_bool_var(), model.new_bool_var());
+    /// model.add_bool_expr(a.implies(b | c) & (b.iff(!c)));
+    /// model.add_bool_expr(a & !b);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(c.solution_value(&response));
+    /// ```
+    pub fn add_bool_expr(&mut self, expr: impl Into<BoolExpr>) {
+        let nnf = self.bool_expr_nnf(&expr.into(), false);
+        let mut literals = Vec::new();
+        self.add_nnf(nnf, &mut literals);
+        if !literals.is_empty() {
+            self.add_and(literals);
+        }
+    }
+
+    /// Returns a literal that is true iff the Boolean formula is true.
+    ///
+    /// A new literal is created with the constraints defining it, as
+    /// for [`CpModelBuilder::add_bool_expr`], unless the formula
+    /// simplifies to a literal, which is then returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let (a, b) = (model.new_bool_var(), model.new_bool_var());
+    /// let both = model.new_bool_expr_var(a & b);
+    /// assert_eq!(!a, model.new_bool_expr_var(!a));
+    /// model.add_and([!both, a]);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(!b.solution_value(&response));
+    /// ```
+    pub fn new_bool_expr_var(&mut self, expr: impl Into<BoolExpr>) -> BoolVar {
+        let nnf = self.bool_expr_nnf(&expr.into(), false);
+        self.nnf_equivalent_literal(nnf)
+    }
+
+    /// Returns the negation normal form of the formula, or of its
+    /// negation. The operands of equivalences are replaced by
+    /// equivalent literals.
+    fn bool_expr_nnf(&mut self, expr: &BoolExpr, negated: bool) -> Nnf {
+        match expr {
+            BoolExpr::Literal(literal) => Nnf::Literal(if negated { !*literal } else { *literal }),
+            BoolExpr::Constant(value) => Nnf::Constant(*value != negated),
+            BoolExpr::Not(expr) => self.bool_expr_nnf(expr, !negated),
+            BoolExpr::And(exprs) | BoolExpr::Or(exprs) => {
+                let children = exprs
+                    .iter()
+                    .map(|expr| self.bool_expr_nnf(expr, negated))
+                    .collect();
+                // De Morgan: the negation swaps conjunctions and disjunctions
+                if matches!(expr, BoolExpr::And(_)) == negated {
+                    Nnf::or(children)
+                } else {
+                    Nnf::and(children)
+                }
+            }
+            BoolExpr::Implies(lhs, rhs) => {
+                let children = vec![
+                    self.bool_expr_nnf(lhs, !negated),
+                    self.bool_expr_nnf(rhs, negated),
+                ];
+                if negated {
+                    Nnf::and(children)
+                } else {
+                    Nnf::or(children)
+                }
+            }
+            BoolExpr::Iff(lhs, rhs) => {
+                let lhs = self.bool_expr_nnf(lhs, false);
+                let lhs = self.nnf_equivalent_literal(lhs);
+                let rhs = self.bool_expr_nnf(rhs, negated);
+                let rhs = self.nnf_equivalent_literal(rhs);
+                Nnf::and(vec![
+                    Nnf::or(vec![Nnf::Literal(!lhs), Nnf::Literal(rhs)]),
+                    Nnf::or(vec![Nnf::Literal(lhs), Nnf::Literal(!rhs)]),
+                ])
+            }
+        }
+    }
+
+    /// Adds the constraints forcing the formula to be true, except for
+    /// the top-level literals that are collected.
+    fn add_nnf(&mut self, nnf: Nnf, literals: &mut Vec<BoolVar>) {
+        match nnf {
+            Nnf::Literal(literal) => literals.push(literal),
+            Nnf::Constant(true) => {}
+            Nnf::Constant(false) => {
+                let literal = self.false_literal();
+                literals.push(literal);
+            }
+            Nnf::And(children) => {
+                for child in children {
+                    self.add_nnf(child, literals);
+                }
+            }
+            Nnf::Or(children) => {
+                let clause: Vec<_> = children
+                    .into_iter()
+                    .map(|child| self.nnf_implying_literal(child))
+                    .collect();
+                self.add_or(clause);
+            }
+        }
+    }
+
+    /// Returns a literal implying the formula.
+    fn nnf_implying_literal(&mut self, nnf: Nnf) -> BoolVar {
+        let (is_and, children) = match nnf {
+            Nnf::Literal(literal) => return literal,
+            Nnf::Constant(true) => return self.true_literal(),
+            Nnf::Constant(false) => return self.false_literal(),
+            Nnf::And(children) => (true, children),
+            Nnf::Or(children) => (false, children),
+        };
+        let literals: Vec<_> = children
+            .into_iter()
+            .map(|child| self.nnf_implying_literal(child))
+            .collect();
+        let indicator = self.new_bool_var();
+        let constraint = if is_and {
+            self.add_and(literals)
+        } else {
+            self.add_or(literals)
+        };
+        self.only_enforce_if(constraint, [indicator]);
+        indicator
+    }
+
+    /// Returns a literal equivalent to the formula.
+    fn nnf_equivalent_literal(&mut self, nnf: Nnf) -> BoolVar {
+        let (is_and, children) = match nnf {
+            Nnf::Literal(literal) => return literal,
+            Nnf::Constant(true) => return self.true_literal(),
+            Nnf::Constant(false) => return self.false_literal(),
+            Nnf::And(children) => (true, children),
+            Nnf::Or(children) => (false, children),
+        };
+        let literals: Vec<_> = children
+            .into_iter()
+            .map(|child| self.nnf_equivalent_literal(child))
+            .collect();
+        let negations: Vec<_> = literals.iter().map(|&literal| !literal).collect();
+        let indicator = self.new_bool_var();
+        // `indicator => and(literals)` and `!indicator => or(!literals)`,
+        // or the dual for a disjunction
+        let (constraint, negation) = if is_and {
+            (self.add_and(literals), self.add_or(negations))
+        } else {
+            (self.add_or(literals), self.add_and(negations))
+        };
+        self.only_enforce_if(constraint, [indicator]);
+        self.only_enforce_if(negation, [!indicator]);
+        indicator
+    }
+
+    /// Adds a "all different" constraint on a list of [`BoolVar`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 2)]);
+    /// let y = model.new_int_var([(0, 2)]);
+    /// let z = model.new_int_var([(0, 2)]);
+    /// model.add_all_different([x, y, z]);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// let x_val = x.solution_value(&response);
+    /// let y_val = y.solution_value(&response);
     /// let z_val = z.solution_value(&response);
     /// assert!(x_val != y_val);
     /// assert!(x_val != z_val);
@@ -416,46 +1316,184 @@ impl CpModelBuilder {
         &mut self,
         exprs: impl IntoIterator<Item = impl Into<LinearExpr>>,
     ) -> Constraint {
-        self.add_cst(CstEnum::AllDiff(proto::AllDifferentConstraintProto {
+        let mut overflow = false;
+        let cst = CstEnum::AllDiff(proto::AllDifferentConstraintProto {
             exprs: exprs
                 .into_iter()
-                .map(Into::into)
-                .map(|expr| proto::LinearExpressionProto {
-                    vars: expr.vars.into_vec(),
-                    coeffs: expr.coeffs.into_vec(),
-                    offset: expr.constant,
-                })
+                .map(|e| expr_proto(&mut overflow, e))
                 .collect(),
-        }))
+        });
+        self.add_checked_cst(cst, overflow)
     }
 
     /// Adds an allowed assignments constraint (also known as a table constraint).
     ///
-    /// The tuple of the given variables must be equal to one of the tuples
-    /// listed in `tuples`.
-    pub fn add_allowed_assignments(&mut self, vars: &[IntVar], tuples: &[Vec<i64>]) -> Constraint {
-        let arity = vars.len();
+    /// The tuple of the values of the given expressions must be equal
+    /// to one of the tuples listed in `tuples`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TupleArity`] if a tuple does not have exactly
+    /// one value per expression.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, Error};
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(0, 10)]);
+    /// model.add_allowed_assignments([x, y], [[1, 2], [3, 4], [5, 0]]).unwrap();
+    /// model.maximize(y);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(3, x.solution_value(&response));
+    /// assert_eq!(4, y.solution_value(&response));
+    ///
+    /// let tuples = vec![vec![1, 2], vec![3]];
+    /// assert_eq!(
+    ///     Err(Error::TupleArity { tuple: 1, expected: 2, actual: 1 }),
+    ///     model.add_allowed_assignments([x, y], &tuples),
+    /// );
+    /// ```
+    pub fn add_allowed_assignments<T: AsRef<[i64]>>(
+        &mut self,
+        exprs: impl IntoIterator<Item = impl Into<LinearExpr>>,
+        tuples: impl IntoIterator<Item = T>,
+    ) -> Result<Constraint, Error> {
+        self.add_table(exprs, tuples, false)
+    }
+
+    /// Adds a forbidden assignments constraint (also known as a negated
+    /// table constraint).
+    ///
+    /// The tuple of the values of the given expressions must be
+    /// different from all the tuples listed in `tuples`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TupleArity`] if a tuple does not have exactly
+    /// one value per expression.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, LinearExpr};
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 1)]);
+    /// let y = model.new_int_var([(0, 1)]);
+    /// model.add_forbidden_assignments([x, y], [[1, 1], [0, 1]]).unwrap();
+    /// model.maximize(LinearExpr::from(x) + y);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(1, x.solution_value(&response));
+    /// assert_eq!(0, y.solution_value(&response));
+    /// ```
+    pub fn add_forbidden_assignments<T: AsRef<[i64]>>(
+        &mut self,
+        exprs: impl IntoIterator<Item = impl Into<LinearExpr>>,
+        tuples: impl IntoIterator<Item = T>,
+    ) -> Result<Constraint, Error> {
+        self.add_table(exprs, tuples, true)
+    }
+
+    fn add_table<T: AsRef<[i64]>>(
+        &mut self,
+        exprs: impl IntoIterator<Item = impl Into<LinearExpr>>,
+        tuples: impl IntoIterator<Item = T>,
+        negated: bool,
+    ) -> Result<Constraint, Error> {
+        let mut overflow = false;
+        let exprs: Vec<_> = exprs
+            .into_iter()
+            .map(|e| expr_proto(&mut overflow, e))
+            .collect();
         let mut values = Vec::new();
-        for tuple in tuples {
-            if tuple.len() != arity {
-                continue;
+        for (index, tuple) in tuples.into_iter().enumerate() {
+            let tuple = tuple.as_ref();
+            if tuple.len() != exprs.len() {
+                return Err(Error::TupleArity {
+                    tuple: index,
+                    expected: exprs.len(),
+                    actual: tuple.len(),
+                });
             }
             values.extend_from_slice(tuple);
         }
 
-        let exprs: Vec<proto::LinearExpressionProto> = vars
-            .iter()
-            .copied()
-            .map(LinearExpr::from)
-            .map(Into::into)
-            .collect();
-
-        self.add_cst(CstEnum::Table(proto::TableConstraintProto {
+        let cst = CstEnum::Table(proto::TableConstraintProto {
             vars: Vec::new(),
             values,
             exprs,
-            negated: false,
-        }))
+            negated,
+        });
+        Ok(self.add_checked_cst(cst, overflow))
+    }
+
+    /// Creates an interval variable, that is always present.
+    ///
+    /// This also enforces `start + size == end`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let start = model.new_int_var([(0, 10)]);
+    /// let size = model.new_int_var([(2, 4)]);
+    /// let end = model.new_int_var([(0, 10)]);
+    /// let interval = model.new_interval_var(start, size, end);
+    /// model.maximize(start);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(8, interval.solution_start(&model, &response));
+    /// assert_eq!(2, interval.solution_size(&model, &response));
+    /// assert_eq!(10, interval.solution_end(&model, &response));
+    /// ```
+    pub fn new_interval_var(
+        &mut self,
+        start: impl Into<LinearExpr>,
+        size: impl Into<LinearExpr>,
+        end: impl Into<LinearExpr>,
+    ) -> IntervalVar {
+        let mut overflow = false;
+        let cst = CstEnum::Interval(proto::IntervalConstraintProto {
+            start: Some(expr_proto(&mut overflow, start)),
+            end: Some(expr_proto(&mut overflow, end)),
+            size: Some(expr_proto(&mut overflow, size)),
+        });
+        let cst = self.add_checked_cst(cst, overflow);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        IntervalVar(cst.0 as i32)
+    }
+
+    /// Creates an interval variable with a fixed size, that is always
+    /// present. Its end is `start + size`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let start = model.new_int_var([(0, 10)]);
+    /// let interval = model.new_fixed_size_interval_var(start, 3);
+    /// model.minimize(start);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(3, interval.solution_end(&model, &response));
+    /// ```
+    pub fn new_fixed_size_interval_var(
+        &mut self,
+        start: impl Into<LinearExpr>,
+        size: i64,
+    ) -> IntervalVar {
+        let start = start.into();
+        let end = start.clone() + size;
+        self.new_interval_var(start, size, end)
     }
 
     /// Creates an optional interval variable.
@@ -472,14 +1510,99 @@ impl CpModelBuilder {
         end: E,
         presence: BoolVar,
     ) -> IntervalVar {
-        let cst = self.add_cst(CstEnum::Interval(proto::IntervalConstraintProto {
-            start: Some(start.into().into()),
-            end: Some(end.into().into()),
-            size: Some(size.into().into()),
-        }));
-        self.only_enforce_if(cst, [presence]);
-        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
-        IntervalVar(cst.0 as i32)
+        let interval = self.new_interval_var(start, size, end);
+        self.only_enforce_if(interval.constraint(), [presence]);
+        interval
+    }
+
+    /// Returns the start of an interval variable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// let start = model.new_int_var([(0, 10)]);
+    /// let interval = model.new_fixed_size_interval_var(start, 3);
+    /// let end = model.interval_end(interval);
+    /// model.add_le(end, model.interval_start(interval) + 5);
+    /// ```
+    #[must_use]
+    pub fn interval_start(&self, interval: IntervalVar) -> LinearExpr {
+        self.interval_proto(interval)
+            .and_then(|interval| interval.start.clone())
+            .map(Into::into)
+            .unwrap_or_default()
+    }
+
+    /// Returns the size of an interval variable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// let start = model.new_int_var([(0, 10)]);
+    /// let size = model.new_int_var([(1, 5)]);
+    /// let end = model.new_int_var([(0, 10)]);
+    /// let interval = model.new_interval_var(start, size, end);
+    /// model.add_ge(model.interval_size(interval), 3);
+    /// ```
+    #[must_use]
+    pub fn interval_size(&self, interval: IntervalVar) -> LinearExpr {
+        self.interval_proto(interval)
+            .and_then(|interval| interval.size.clone())
+            .map(Into::into)
+            .unwrap_or_default()
+    }
+
+    /// Returns the end of an interval variable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// let start = model.new_int_var([(0, 10)]);
+    /// let interval = model.new_fixed_size_interval_var(start, 3);
+    /// model.add_le(model.interval_end(interval), 8);
+    /// ```
+    #[must_use]
+    pub fn interval_end(&self, interval: IntervalVar) -> LinearExpr {
+        self.interval_proto(interval)
+            .and_then(|interval| interval.end.clone())
+            .map(Into::into)
+            .unwrap_or_default()
+    }
+
+    /// Returns the presence literal of an interval variable, or `None`
+    /// if the interval is always present.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// let start = model.new_int_var([(0, 10)]);
+    /// let end = model.new_int_var([(0, 10)]);
+    /// let present = model.new_bool_var();
+    /// let optional = model.new_optional_interval_var(start, 2, end, present);
+    /// assert_eq!(Some(present), model.interval_presence(optional));
+    /// let interval = model.new_fixed_size_interval_var(start, 2);
+    /// assert_eq!(None, model.interval_presence(interval));
+    /// ```
+    #[must_use]
+    pub fn interval_presence(&self, interval: IntervalVar) -> Option<BoolVar> {
+        self.constraint_proto(interval.constraint())
+            .and_then(|cst| cst.enforcement_literal.first())
+            .map(|&literal| BoolVar(literal))
+    }
+
+    fn interval_proto(&self, interval: IntervalVar) -> Option<&proto::IntervalConstraintProto> {
+        match self.constraint_body(interval.constraint()) {
+            Some(CstEnum::Interval(interval)) => Some(interval),
+            _ => None,
+        }
     }
 
     /// Adds a no-overlap constraint on the given intervals.
@@ -489,49 +1612,642 @@ impl CpModelBuilder {
         }))
     }
 
+    /// Adds a 2D no-overlap constraint on the given rectangles.
+    ///
+    /// Each rectangle is given by its interval on the x axis and its
+    /// interval on the y axis, and no two rectangles can overlap. A
+    /// rectangle is ignored as soon as one of its intervals is not
+    /// present.
+    ///
+    /// More rectangles can be added afterwards with
+    /// [`CpModelBuilder::add_no_overlap_2d_rectangle`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let present = model.new_bool_var();
+    /// model.add_and([present]);
+    /// let mut rectangles = Vec::new();
+    /// let mut x_starts = Vec::new();
+    /// for _ in 0..2 {
+    ///     let x_start = model.new_int_var([(0, 2)]);
+    ///     let x_end = model.new_int_var([(0, 4)]);
+    ///     let y_start = model.new_int_var([(0, 0)]);
+    ///     let y_end = model.new_int_var([(0, 2)]);
+    ///     let x = model.new_optional_interval_var(x_start, 2, x_end, present);
+    ///     let y = model.new_optional_interval_var(y_start, 2, y_end, present);
+    ///     rectangles.push((x, y));
+    ///     x_starts.push(x_start);
+    /// }
+    /// model.add_no_overlap_2d(&rectangles);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// let x0 = x_starts[0].solution_value(&response);
+    /// let x1 = x_starts[1].solution_value(&response);
+    /// assert_eq!(2, (x0 - x1).abs());
+    /// ```
+    pub fn add_no_overlap_2d(
+        &mut self,
+        rectangles: &[(IntervalVar, IntervalVar)],
+    ) -> NoOverlap2DConstraint {
+        NoOverlap2DConstraint(self.add_cst(CstEnum::NoOverlap2d(
+            proto::NoOverlap2DConstraintProto {
+                x_intervals: rectangles.iter().map(|(x, _)| x.0).collect(),
+                y_intervals: rectangles.iter().map(|(_, y)| y.0).collect(),
+            },
+        )))
+    }
+
+    /// Adds a rectangle to an existing 2D no-overlap constraint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let present = model.new_bool_var();
+    /// model.add_and([present]);
+    /// let no_overlap = model.add_no_overlap_2d(&[]);
+    /// let mut y_starts = Vec::new();
+    /// for _ in 0..2 {
+    ///     let x_start = model.new_int_var([(0, 0)]);
+    ///     let x_end = model.new_int_var([(0, 1)]);
+    ///     let y_start = model.new_int_var([(0, 1)]);
+    ///     let y_end = model.new_int_var([(0, 2)]);
+    ///     let x = model.new_optional_interval_var(x_start, 1, x_end, present);
+    ///     let y = model.new_optional_interval_var(y_start, 1, y_end, present);
+    ///     model.add_no_overlap_2d_rectangle(no_overlap, x, y);
+    ///     y_starts.push(y_start);
+    /// }
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_ne!(
+    ///     y_starts[0].solution_value(&response),
+    ///     y_starts[1].solution_value(&response)
+    /// );
+    /// ```
+    pub fn add_no_overlap_2d_rectangle(
+        &mut self,
+        no_overlap: NoOverlap2DConstraint,
+        x: IntervalVar,
+        y: IntervalVar,
+    ) {
+        if let Some(CstEnum::NoOverlap2d(cst)) = self.constraint_body_mut(no_overlap.into()) {
+            cst.x_intervals.push(x.0);
+            cst.y_intervals.push(y.0);
+        }
+    }
+
+    /// Adds a cumulative constraint on the given intervals.
+    ///
+    /// At any time, the sum of the demands of the present intervals
+    /// that overlap this time must not exceed `capacity`. Each item of
+    /// `demands` is an interval with its demand, both the capacity and
+    /// the demands can be any [`LinearExpr`].
+    ///
+    /// More intervals can be added afterwards with
+    /// [`CpModelBuilder::add_cumulative_demand`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let present = model.new_bool_var();
+    /// model.add_and([present]);
+    /// let mut ends = Vec::new();
+    /// let mut demands = Vec::new();
+    /// for _ in 0..3 {
+    ///     let start = model.new_int_var([(0, 10)]);
+    ///     let end = model.new_int_var([(0, 10)]);
+    ///     let interval = model.new_optional_interval_var(start, 3, end, present);
+    ///     ends.push(end);
+    ///     demands.push((interval, 2));
+    /// }
+    /// model.add_cumulative(4, demands);
+    /// let makespan = model.new_int_var([(0, 10)]);
+    /// model.add_max_eq(makespan, ends);
+    /// model.minimize(makespan);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(6, makespan.solution_value(&response));
+    /// ```
+    pub fn add_cumulative<D: Into<LinearExpr>>(
+        &mut self,
+        capacity: impl Into<LinearExpr>,
+        demands: impl IntoIterator<Item = (IntervalVar, D)>,
+    ) -> CumulativeConstraint {
+        let mut overflow = false;
+        let (intervals, demands) = demands
+            .into_iter()
+            .map(|(interval, demand)| (interval.0, expr_proto(&mut overflow, demand)))
+            .unzip();
+        let cst = CstEnum::Cumulative(proto::CumulativeConstraintProto {
+            capacity: Some(expr_proto(&mut overflow, capacity)),
+            intervals,
+            demands,
+        });
+        CumulativeConstraint(self.add_checked_cst(cst, overflow))
+    }
+
+    /// Adds an interval with its demand to an existing cumulative
+    /// constraint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let present = model.new_bool_var();
+    /// model.add_and([present]);
+    /// let capacity = model.new_int_var([(0, 10)]);
+    /// let cumulative = model.add_cumulative::<i64>(capacity, []);
+    /// for demand in [2, 3] {
+    ///     let start = model.new_int_var([(0, 0)]);
+    ///     let end = model.new_int_var([(0, 10)]);
+    ///     let interval = model.new_optional_interval_var(start, 1, end, present);
+    ///     model.add_cumulative_demand(cumulative, interval, demand);
+    /// }
+    /// model.minimize(capacity);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(5, capacity.solution_value(&response));
+    /// ```
+    pub fn add_cumulative_demand(
+        &mut self,
+        cumulative: CumulativeConstraint,
+        interval: IntervalVar,
+        demand: impl Into<LinearExpr>,
+    ) {
+        let mut overflow = false;
+        let demand = expr_proto(&mut overflow, demand);
+        if let Some(CstEnum::Cumulative(cst)) = self.constraint_body_mut(cumulative.into()) {
+            cst.intervals.push(interval.0);
+            cst.demands.push(demand);
+            self.record_overflow(cumulative.into(), overflow);
+        }
+    }
+
+    /// Adds a reservoir constraint, and returns a handle to add events
+    /// to it with [`CpModelBuilder::add_reservoir_event`].
+    ///
+    /// The level of the reservoir starts at zero, and each event
+    /// changes it at a given time. At any time, the level must stay in
+    /// `[min_level, max_level]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ReservoirLevels`] if the initial level of zero
+    /// is not in `[min_level, max_level]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, Error};
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let fill = model.new_int_var([(0, 10)]);
+    /// let drain = model.new_int_var([(0, 10)]);
+    /// let reservoir = model.add_reservoir(0, 5).unwrap();
+    /// model.add_reservoir_event(reservoir, fill, 5, None);
+    /// model.add_reservoir_event(reservoir, drain, -5, None);
+    /// model.minimize(fill);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// // the tank cannot be drained before being filled
+    /// assert!(fill.solution_value(&response) <= drain.solution_value(&response));
+    ///
+    /// assert_eq!(
+    ///     Err(Error::ReservoirLevels { min_level: 2, max_level: 5 }),
+    ///     model.add_reservoir(2, 5),
+    /// );
+    /// ```
+    pub fn add_reservoir(
+        &mut self,
+        min_level: i64,
+        max_level: i64,
+    ) -> Result<ReservoirConstraint, Error> {
+        if min_level > 0 || max_level < 0 {
+            return Err(Error::ReservoirLevels {
+                min_level,
+                max_level,
+            });
+        }
+        Ok(ReservoirConstraint(self.add_cst(CstEnum::Reservoir(
+            proto::ReservoirConstraintProto {
+                min_level,
+                max_level,
+                ..Default::default()
+            },
+        ))))
+    }
+
+    /// Adds an event to a reservoir constraint: at `time`, the level
+    /// of the reservoir changes by `level_change`.
+    ///
+    /// If `active` is given, the event only happens when this literal
+    /// is true.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let charge = model.new_bool_var();
+    /// let reservoir = model.add_reservoir(0, 10).unwrap();
+    /// model.add_reservoir_event(reservoir, 0, 4, Some(charge));
+    /// model.add_reservoir_event(reservoir, 1, -3, None);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// // the battery must be charged before being used
+    /// assert!(charge.solution_value(&response));
+    /// ```
+    pub fn add_reservoir_event(
+        &mut self,
+        reservoir: ReservoirConstraint,
+        time: impl Into<LinearExpr>,
+        level_change: impl Into<LinearExpr>,
+        active: Option<BoolVar>,
+    ) {
+        let Some(CstEnum::Reservoir(cst)) = self.constraint_body(reservoir.into()) else {
+            return;
+        };
+        // active literals are given for all the events or for none of
+        // them, so mandatory events need one as soon as one is optional
+        let always = match active {
+            None if !cst.active_literals.is_empty() => Some(self.true_literal()),
+            Some(_) if cst.active_literals.len() < cst.time_exprs.len() => {
+                Some(self.true_literal())
+            }
+            _ => None,
+        };
+
+        let mut overflow = false;
+        let (time, level_change) = (
+            expr_proto(&mut overflow, time),
+            expr_proto(&mut overflow, level_change),
+        );
+        if let Some(CstEnum::Reservoir(cst)) = self.constraint_body_mut(reservoir.into()) {
+            cst.time_exprs.push(time);
+            cst.level_changes.push(level_change);
+            if let Some(always) = always {
+                let missing = cst.time_exprs.len()
+                    - cst.active_literals.len()
+                    - usize::from(active.is_some());
+                cst.active_literals
+                    .extend(std::iter::repeat_n(always.0, missing));
+            }
+            if let Some(active) = active {
+                cst.active_literals.push(active.0);
+            }
+            self.record_overflow(reservoir.into(), overflow);
+        }
+    }
+
+    /// Adds a circuit constraint on a graph given by its arcs.
+    ///
+    /// Each arc is given by its tail node, its head node and a literal
+    /// that is true iff the arc is selected. The selected arcs must
+    /// form a single circuit going through every node, except for the
+    /// nodes `n` whose self-loop arc `(n, n)` is selected: these nodes
+    /// are skipped.
+    ///
+    /// The tour can be read back from a response with
+    /// [`CpModelBuilder::circuit_tour`], and more arcs can be added
+    /// afterwards with [`CpModelBuilder::add_circuit_arc`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let mut arcs = Vec::new();
+    /// for tail in 0..3 {
+    ///     for head in 0..3 {
+    ///         if tail != head {
+    ///             arcs.push((tail, head, model.new_bool_var()));
+    ///         }
+    ///     }
+    /// }
+    /// let circuit = model.add_circuit(arcs);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// let tour = model.circuit_tour(circuit, &response);
+    /// assert_eq!(3, tour.len());
+    /// assert_eq!(0, tour[0]);
+    /// ```
+    pub fn add_circuit(
+        &mut self,
+        arcs: impl IntoIterator<Item = (i32, i32, BoolVar)>,
+    ) -> CircuitConstraint {
+        let mut circuit = proto::CircuitConstraintProto::default();
+        for (tail, head, literal) in arcs {
+            circuit.tails.push(tail);
+            circuit.heads.push(head);
+            circuit.literals.push(literal.0);
+        }
+        CircuitConstraint(self.add_cst(CstEnum::Circuit(circuit)))
+    }
+
+    /// Adds an arc to an existing circuit constraint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let circuit = model.add_circuit([]);
+    /// for (tail, head) in [(0, 1), (1, 2), (2, 0)] {
+    ///     let literal = model.new_bool_var();
+    ///     model.add_circuit_arc(circuit, tail, head, literal);
+    /// }
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(vec![0, 1, 2], model.circuit_tour(circuit, &response));
+    /// ```
+    pub fn add_circuit_arc(
+        &mut self,
+        circuit: CircuitConstraint,
+        tail: i32,
+        head: i32,
+        literal: BoolVar,
+    ) {
+        if let Some(CstEnum::Circuit(cst)) = self.constraint_body_mut(circuit.into()) {
+            cst.tails.push(tail);
+            cst.heads.push(head);
+            cst.literals.push(literal.0);
+        }
+    }
+
+    /// Adds a multiple circuit constraint, also known as a routes
+    /// constraint, on a graph given by its arcs.
+    ///
+    /// Node 0 is the depot. The selected arcs must form a set of
+    /// circuits that all go through the depot, and every other node
+    /// must be visited by exactly one of them, except for the nodes
+    /// `n` whose self-loop arc `(n, n)` is selected. Each circuit can
+    /// be seen as the route of one vehicle.
+    ///
+    /// The routes can be read back from a response with
+    /// [`CpModelBuilder::multiple_circuit_routes`], and more arcs can
+    /// be added afterwards with
+    /// [`CpModelBuilder::add_multiple_circuit_arc`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, LinearExpr};
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let mut arcs = Vec::new();
+    /// let mut from_depot = Vec::new();
+    /// for tail in 0..5 {
+    ///     for head in 0..5 {
+    ///         if tail != head {
+    ///             let literal = model.new_bool_var();
+    ///             if tail == 0 {
+    ///                 from_depot.push(literal);
+    ///             }
+    ///             arcs.push((tail, head, literal));
+    ///         }
+    ///     }
+    /// }
+    /// // exactly two vehicles leave the depot
+    /// model.add_eq(from_depot.iter().copied().collect::<LinearExpr>(), 2);
+    /// let routes = model.add_multiple_circuit(arcs);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// let routes = model.multiple_circuit_routes(routes, &response);
+    /// assert_eq!(2, routes.len());
+    /// assert_eq!(4, routes.iter().map(Vec::len).sum::<usize>());
+    /// ```
+    pub fn add_multiple_circuit(
+        &mut self,
+        arcs: impl IntoIterator<Item = (i32, i32, BoolVar)>,
+    ) -> MultipleCircuitConstraint {
+        let mut routes = proto::RoutesConstraintProto::default();
+        for (tail, head, literal) in arcs {
+            routes.tails.push(tail);
+            routes.heads.push(head);
+            routes.literals.push(literal.0);
+        }
+        MultipleCircuitConstraint(self.add_cst(CstEnum::Routes(routes)))
+    }
+
+    /// Adds an arc to an existing multiple circuit constraint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let routes = model.add_multiple_circuit([]);
+    /// for (tail, head) in [(0, 1), (1, 0), (0, 2), (2, 0)] {
+    ///     let literal = model.new_bool_var();
+    ///     model.add_multiple_circuit_arc(routes, tail, head, literal);
+    /// }
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(
+    ///     vec![vec![1], vec![2]],
+    ///     model.multiple_circuit_routes(routes, &response)
+    /// );
+    /// ```
+    pub fn add_multiple_circuit_arc(
+        &mut self,
+        routes: MultipleCircuitConstraint,
+        tail: i32,
+        head: i32,
+        literal: BoolVar,
+    ) {
+        if let Some(CstEnum::Routes(cst)) = self.constraint_body_mut(routes.into()) {
+            cst.tails.push(tail);
+            cst.heads.push(head);
+            cst.literals.push(literal.0);
+        }
+    }
+
+    /// Returns the nodes of the circuit selected in the given
+    /// response, in visiting order.
+    ///
+    /// The tour starts from the smallest visited node, and skipped
+    /// nodes are not part of it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let mut arcs = Vec::new();
+    /// for tail in 0..4 {
+    ///     for head in 0..4 {
+    ///         arcs.push((tail, head, model.new_bool_var()));
+    ///     }
+    /// }
+    /// // node 2 is skipped
+    /// let skip = arcs[2 * 4 + 2].2;
+    /// model.add_and([skip]);
+    /// let circuit = model.add_circuit(arcs);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// let tour = model.circuit_tour(circuit, &response);
+    /// assert!(!tour.contains(&2));
+    /// ```
+    #[must_use]
+    pub fn circuit_tour(
+        &self,
+        circuit: CircuitConstraint,
+        response: &proto::CpSolverResponse,
+    ) -> Vec<i32> {
+        let Some(CstEnum::Circuit(cst)) = self.constraint_body(circuit.into()) else {
+            return Vec::new();
+        };
+        let successors = selected_arcs(&cst.tails, &cst.heads, &cst.literals, response)
+            .collect::<std::collections::BTreeMap<_, _>>();
+        let Some(&start) = successors.keys().next() else {
+            return Vec::new();
+        };
+        let mut tour = vec![start];
+        let mut node = start;
+        while let Some(&next) = successors.get(&node) {
+            if next == start || tour.len() > successors.len() {
+                break;
+            }
+            tour.push(next);
+            node = next;
+        }
+        tour
+    }
+
+    /// Returns the routes selected in the given response for a
+    /// multiple circuit constraint.
+    ///
+    /// Each route lists the nodes visited by one vehicle in visiting
+    /// order, without the depot. Routes are sorted by their first
+    /// node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let mut arcs = Vec::new();
+    /// for (tail, head) in [(0, 1), (1, 2), (2, 0), (0, 3), (3, 0)] {
+    ///     let literal = model.new_bool_var();
+    ///     arcs.push((tail, head, literal));
+    /// }
+    /// let routes = model.add_multiple_circuit(arcs);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(
+    ///     vec![vec![1, 2], vec![3]],
+    ///     model.multiple_circuit_routes(routes, &response)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn multiple_circuit_routes(
+        &self,
+        routes: MultipleCircuitConstraint,
+        response: &proto::CpSolverResponse,
+    ) -> Vec<Vec<i32>> {
+        let Some(CstEnum::Routes(cst)) = self.constraint_body(routes.into()) else {
+            return Vec::new();
+        };
+        let mut starts = Vec::new();
+        let mut successors = std::collections::BTreeMap::new();
+        for (tail, head) in selected_arcs(&cst.tails, &cst.heads, &cst.literals, response) {
+            if tail == 0 {
+                starts.push(head);
+            } else {
+                successors.insert(tail, head);
+            }
+        }
+        starts.sort_unstable();
+        starts
+            .into_iter()
+            .map(|start| {
+                let mut route = Vec::new();
+                let mut node = start;
+                while node != 0 && route.len() <= successors.len() {
+                    route.push(node);
+                    node = successors.get(&node).copied().unwrap_or(0);
+                }
+                route
+            })
+            .collect()
+    }
+
     /// Adds a linear constraint.
     ///
+    /// The domain is given either as a [`Domain`] or as a list of
+    /// intervals, as for [`CpModelBuilder::new_int_var`]. The
+    /// expression is [canonicalized][LinearExpr::canonicalize] first:
+    /// if it overflows, the model becomes invalid, see
+    /// [`CpModelBuilder::validate_cp_model`].
+    ///
     /// # Example
     ///
     /// ```
     /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::Domain;
     /// # use or_tools::proto::CpSolverStatus;
     /// let mut model = CpModelBuilder::default();
     /// let x = model.new_int_var([(0, 100)]);
     /// let y = model.new_int_var([(0, 100)]);
     /// model.add_linear_constraint([(1, x), (3, y)], [(301, i64::MAX)]);
+    /// model.add_linear_constraint([(1, x), (-1, y)], Domain::from_values([0, 10]).complement());
     /// let response = model.solve();
     /// assert_eq!(response.status(), CpSolverStatus::Optimal);
-    /// assert!(x.solution_value(&response) + 3 * y.solution_value(&response) >= 301);
+    /// let (x, y) = (x.solution_value(&response), y.solution_value(&response));
+    /// assert!(x + 3 * y >= 301);
+    /// assert!(x - y != 0 && x - y != 10);
     /// ```
     pub fn add_linear_constraint(
         &mut self,
         expr: impl Into<LinearExpr>,
-        domain: impl IntoIterator<Item = (i64, i64)>,
+        domain: impl Into<Domain>,
     ) -> Constraint {
-        let expr = expr.into();
+        let mut overflow = false;
+        let expr = canonicalized(&mut overflow, expr.into());
         let constant = expr.constant;
-        self.add_cst(CstEnum::Linear(proto::LinearConstraintProto {
-            vars: expr.vars.into_vec(),
-            coeffs: expr.coeffs.into_vec(),
-            domain: domain
-                .into_iter()
-                .flat_map(|(begin, end)| {
-                    [
-                        if begin == i64::MIN {
-                            i64::MIN
-                        } else {
-                            begin.saturating_sub(constant)
-                        },
-                        if end == i64::MAX {
-                            i64::MAX
-                        } else {
-                            end.saturating_sub(constant)
-                        },
-                    ]
-                })
-                .collect(),
-        }))
+        self.add_checked_cst(
+            CstEnum::Linear(proto::LinearConstraintProto {
+                vars: expr.vars.into_vec(),
+                coeffs: expr.coeffs.into_vec(),
+                domain: domain
+                    .into()
+                    .intervals()
+                    .iter()
+                    .flat_map(|&(begin, end)| {
+                        [
+                            if begin == i64::MIN {
+                                i64::MIN
+                            } else {
+                                begin.saturating_sub(constant)
+                            },
+                            if end == i64::MAX {
+                                i64::MAX
+                            } else {
+                                end.saturating_sub(constant)
+                            },
+                        ]
+                    })
+                    .collect(),
+            }),
+            overflow,
+        )
     }
 
     /// Adds an equality constraint between 2 linear expressions.
@@ -647,19 +2363,451 @@ impl CpModelBuilder {
     /// model.add_lt(x, y);
     /// let response = model.solve();
     /// assert_eq!(response.status(), CpSolverStatus::Optimal);
-    /// assert!(x.solution_value(&response) < y.solution_value(&response));
-    /// assert_eq!(50, x.solution_value(&response));
-    /// assert_eq!(51, y.solution_value(&response));
+    /// assert!(x.solution_value(&response) < y.solution_value(&response));
+    /// assert_eq!(50, x.solution_value(&response));
+    /// assert_eq!(51, y.solution_value(&response));
+    /// ```
+    pub fn add_lt<T: Into<LinearExpr>, U: Into<LinearExpr>>(
+        &mut self,
+        lhs: T,
+        rhs: U,
+    ) -> Constraint {
+        self.add_linear_constraint(lhs.into() - rhs.into(), [(i64::MIN, -1)])
+    }
+
+    /// Adds a not equal constraint between 2 linear expressions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, LinearExpr};
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_bool_var();
+    /// model.add_ne(x, 1);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(x.solution_value(&response) as i64 != 1);
+    /// assert!(!x.solution_value(&response));
+    /// ```
+    pub fn add_ne<T: Into<LinearExpr>, U: Into<LinearExpr>>(
+        &mut self,
+        lhs: T,
+        rhs: U,
+    ) -> Constraint {
+        self.add_linear_constraint(lhs.into() - rhs.into(), [(i64::MIN, -1), (1, i64::MAX)])
+    }
+
+    /// Returns a new literal that is true iff `lhs == rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let is_five = model.new_reified_eq(x, 5);
+    /// model.add_and([!is_five]);
+    /// model.maximize(x);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(10, x.solution_value(&response));
+    /// ```
+    pub fn new_reified_eq<T: Into<LinearExpr>, U: Into<LinearExpr>>(
+        &mut self,
+        lhs: T,
+        rhs: U,
+    ) -> BoolVar {
+        self.new_reified_in_domain(lhs.into() - rhs.into(), [(0, 0)])
+    }
+
+    /// Returns a new literal that is true iff `lhs <= rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let small = model.new_reified_le(x, 3);
+    /// model.maximize(x + 20 * small);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(3, x.solution_value(&response));
+    /// assert!(small.solution_value(&response));
+    /// ```
+    pub fn new_reified_le<T: Into<LinearExpr>, U: Into<LinearExpr>>(
+        &mut self,
+        lhs: T,
+        rhs: U,
+    ) -> BoolVar {
+        self.new_reified_in_domain(lhs.into() - rhs.into(), [(i64::MIN, 0)])
+    }
+
+    /// Returns a new literal that is true iff `lhs >= rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let large = model.new_reified_ge(x, 8);
+    /// model.minimize(x - 20 * large);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(8, x.solution_value(&response));
+    /// assert!(large.solution_value(&response));
+    /// ```
+    pub fn new_reified_ge<T: Into<LinearExpr>, U: Into<LinearExpr>>(
+        &mut self,
+        lhs: T,
+        rhs: U,
+    ) -> BoolVar {
+        self.new_reified_in_domain(lhs.into() - rhs.into(), [(0, i64::MAX)])
+    }
+
+    /// Returns a new literal that is true iff `lhs != rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(0, 10)]);
+    /// let differ = model.new_reified_ne(x, y);
+    /// model.add_and([!differ]);
+    /// model.maximize(x - y);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!((response.objective_value - 0.).abs() < 1e-9);
+    /// ```
+    pub fn new_reified_ne<T: Into<LinearExpr>, U: Into<LinearExpr>>(
+        &mut self,
+        lhs: T,
+        rhs: U,
+    ) -> BoolVar {
+        self.new_reified_in_domain(lhs.into() - rhs.into(), [(i64::MIN, -1), (1, i64::MAX)])
+    }
+
+    /// Returns a new literal that is true iff the value of the
+    /// expression is in the domain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::Domain;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let weekend = model.new_reified_in_domain(x, Domain::from_values([5, 6]));
+    /// model.add_and([weekend]);
+    /// model.maximize(x);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(6, x.solution_value(&response));
+    /// ```
+    pub fn new_reified_in_domain(
+        &mut self,
+        expr: impl Into<LinearExpr>,
+        domain: impl Into<Domain>,
+    ) -> BoolVar {
+        let (expr, domain) = (expr.into(), domain.into());
+        let indicator = self.new_bool_var();
+        let constraint = self.add_linear_constraint(expr.clone(), domain.complement());
+        self.only_enforce_if(constraint, [!indicator]);
+        let constraint = self.add_linear_constraint(expr, domain);
+        self.only_enforce_if(constraint, [indicator]);
+        indicator
+    }
+
+    /// Returns a new literal that is true iff the linear constraint
+    /// added by `build` holds.
+    ///
+    /// The constraint is enforced by the literal, and its negation,
+    /// i.e. the same expression constrained to the complement of the
+    /// domain, is enforced by the negation of the literal. This covers
+    /// all the comparisons and domain memberships.
+    ///
+    /// The auxiliary variables and constraints that `build` may add are
+    /// not reified: if the constraint relies on them, like
+    /// `x == 2 * y + 1` with a free `y` to express that `x` is odd, the
+    /// literal being true still implies the constraint, but the
+    /// literal may be false even if the constraint could be satisfied.
+    ///
+    /// `build` must only add variables and constraints: the objective,
+    /// hints, search strategies and variable domains it may change are
+    /// not restored if the reification fails, and would then refer to
+    /// removed variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotReifiable`] if the constraint is not a
+    /// linear constraint, or if it is already enforced. The variables
+    /// and constraints added by `build` are then removed from the
+    /// model.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, Error};
+    /// # use or_tools::linear;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(0, 10)]);
+    /// let in_band = model.reify(|m| linear!(m, 3 <= x - y <= 5))?;
+    /// model.add_and([in_band]);
+    /// model.maximize(x + y);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(10, x.solution_value(&response));
+    /// assert_eq!(7, y.solution_value(&response));
+    ///
+    /// let b = model.new_bool_var();
+    /// assert_eq!(Some(Error::NotReifiable), model.reify(|m| m.add_or([b])).err());
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn reify<C: Into<Constraint>>(
+        &mut self,
+        build: impl FnOnce(&mut Self) -> C,
+    ) -> Result<BoolVar, Error> {
+        let (num_vars, num_constraints) =
+            (self.proto.variables.len(), self.proto.constraints.len());
+        let constraint = build(self).into();
+        #[allow(clippy::indexing_slicing)]
+        let negation = match &self.proto.constraints[constraint.0] {
+            proto::ConstraintProto {
+                enforcement_literal,
+                constraint: Some(CstEnum::Linear(linear)),
+                ..
+            } if enforcement_literal.is_empty() => proto::LinearConstraintProto {
+                vars: linear.vars.clone(),
+                coeffs: linear.coeffs.clone(),
+                domain: Domain::from_flat_intervals(&linear.domain)
+                    .complement()
+                    .to_flat_intervals(),
+            },
+            _ => {
+                self.truncate(num_vars, num_constraints);
+                return Err(Error::NotReifiable);
+            }
+        };
+        let indicator = self.new_bool_var();
+        self.only_enforce_if(constraint, [indicator]);
+        let overflow = self.overflowed.contains(&constraint.0);
+        let negation = self.add_checked_cst(CstEnum::Linear(negation), overflow);
+        self.only_enforce_if(negation, [!indicator]);
+        Ok(indicator)
+    }
+
+    /// Adds a constraint that force the `target` to be equal to the
+    /// minimum of the given `exprs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, LinearExpr};
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(5, 15)]);
+    /// let m = model.new_int_var([(-100, 100)]);
+    /// model.add_min_eq(m, [x, y]);
+    /// model.maximize(m);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(10., response.objective_value);
+    /// assert_eq!(10, m.solution_value(&response));
+    /// ```
+    pub fn add_min_eq(
+        &mut self,
+        target: impl Into<LinearExpr>,
+        exprs: impl IntoIterator<Item = impl Into<LinearExpr>>,
+    ) -> Constraint {
+        let mut overflow = false;
+        let cst = CstEnum::LinMax(proto::LinearArgumentProto {
+            target: Some(expr_proto(&mut overflow, -target.into())),
+            exprs: exprs
+                .into_iter()
+                .map(|e| expr_proto(&mut overflow, -e.into()))
+                .collect(),
+        });
+        self.add_checked_cst(cst, overflow)
+    }
+
+    /// Adds a constraint that force the `target` to be equal to the
+    /// maximum of the given `exprs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, LinearExpr};
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(5, 15)]);
+    /// let m = model.new_int_var([(-100, 100)]);
+    /// model.add_max_eq(m, [x, y]);
+    /// model.minimize(m);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(5., response.objective_value);
+    /// assert_eq!(5, m.solution_value(&response));
+    /// ```
+    pub fn add_max_eq(
+        &mut self,
+        target: impl Into<LinearExpr>,
+        exprs: impl IntoIterator<Item = impl Into<LinearExpr>>,
+    ) -> Constraint {
+        let mut overflow = false;
+        let cst = CstEnum::LinMax(proto::LinearArgumentProto {
+            target: Some(expr_proto(&mut overflow, target)),
+            exprs: exprs
+                .into_iter()
+                .map(|e| expr_proto(&mut overflow, e))
+                .collect(),
+        });
+        self.add_checked_cst(cst, overflow)
+    }
+
+    /// Adds a constraint that force the `target` to be equal to the
+    /// product of the given `exprs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, LinearExpr};
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(-3, 4)]);
+    /// let y = model.new_int_var([(-5, 2)]);
+    /// let p = model.new_int_var([(-100, 100)]);
+    /// model.add_multiplication_eq(p, [x, y]);
+    /// model.maximize(p);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(15., response.objective_value);
+    /// assert_eq!(-3, x.solution_value(&response));
+    /// assert_eq!(-5, y.solution_value(&response));
+    /// ```
+    pub fn add_multiplication_eq(
+        &mut self,
+        target: impl Into<LinearExpr>,
+        exprs: impl IntoIterator<Item = impl Into<LinearExpr>>,
+    ) -> Constraint {
+        let mut overflow = false;
+        let cst = CstEnum::IntProd(proto::LinearArgumentProto {
+            target: Some(expr_proto(&mut overflow, target)),
+            exprs: exprs
+                .into_iter()
+                .map(|e| expr_proto(&mut overflow, e))
+                .collect(),
+        });
+        self.add_checked_cst(cst, overflow)
+    }
+
+    /// Adds a constraint that force the `target` to be equal to
+    /// `numerator / denominator`, rounded towards zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DivisionByZero`] if the domain of the
+    /// `denominator` contains zero. The check is exact for constants
+    /// and expressions with a single variable, and uses the bounds of
+    /// the expression otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, Error};
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 20)]);
+    /// let d = model.new_int_var([(-3, -1), (1, 3)]);
+    /// let q = model.new_int_var([(-100, 100)]);
+    /// model.add_division_eq(q, x, d).unwrap();
+    /// model.minimize(q);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(-20., response.objective_value);
+    ///
+    /// let z = model.new_int_var([(-1, 1)]);
+    /// assert_eq!(Err(Error::DivisionByZero), model.add_division_eq(q, x, z));
+    /// ```
+    pub fn add_division_eq(
+        &mut self,
+        target: impl Into<LinearExpr>,
+        numerator: impl Into<LinearExpr>,
+        denominator: impl Into<LinearExpr>,
+    ) -> Result<Constraint, Error> {
+        let denominator = denominator.into();
+        if self.can_be_zero(&denominator) {
+            return Err(Error::DivisionByZero);
+        }
+        let mut overflow = false;
+        let cst = CstEnum::IntDiv(proto::LinearArgumentProto {
+            target: Some(expr_proto(&mut overflow, target)),
+            exprs: vec![
+                expr_proto(&mut overflow, numerator),
+                expr_proto(&mut overflow, denominator),
+            ],
+        });
+        Ok(self.add_checked_cst(cst, overflow))
+    }
+
+    /// Adds a constraint that force the `target` to be equal to
+    /// `expr % modulus`, with the sign of `expr`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DivisionByZero`] if the domain of the `modulus`
+    /// contains zero, see [`CpModelBuilder::add_division_eq`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 20)]);
+    /// let r = model.new_int_var([(0, 100)]);
+    /// model.add_modulo_eq(r, x, 7).unwrap();
+    /// model.maximize(r);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(6., response.objective_value);
+    /// assert_eq!(6, x.solution_value(&response) % 7);
     /// ```
-    pub fn add_lt<T: Into<LinearExpr>, U: Into<LinearExpr>>(
+    pub fn add_modulo_eq(
         &mut self,
-        lhs: T,
-        rhs: U,
-    ) -> Constraint {
-        self.add_linear_constraint(lhs.into() - rhs.into(), [(i64::MIN, -1)])
+        target: impl Into<LinearExpr>,
+        expr: impl Into<LinearExpr>,
+        modulus: impl Into<LinearExpr>,
+    ) -> Result<Constraint, Error> {
+        let modulus = modulus.into();
+        if self.can_be_zero(&modulus) {
+            return Err(Error::DivisionByZero);
+        }
+        let mut overflow = false;
+        let cst = CstEnum::IntMod(proto::LinearArgumentProto {
+            target: Some(expr_proto(&mut overflow, target)),
+            exprs: vec![
+                expr_proto(&mut overflow, expr),
+                expr_proto(&mut overflow, modulus),
+            ],
+        });
+        Ok(self.add_checked_cst(cst, overflow))
     }
 
-    /// Adds a not equal constraint between 2 linear expressions.
+    /// Adds a constraint that force the `target` to be equal to the
+    /// absolute value of `expr`.
     ///
     /// # Example
     ///
@@ -667,23 +2815,29 @@ impl CpModelBuilder {
     /// # use or_tools::builder::{CpModelBuilder, LinearExpr};
     /// # use or_tools::proto::CpSolverStatus;
     /// let mut model = CpModelBuilder::default();
-    /// let x = model.new_bool_var();
-    /// model.add_ne(x, 1);
+    /// let x = model.new_int_var([(-10, 5)]);
+    /// let a = model.new_int_var([(0, 100)]);
+    /// model.add_abs_eq(a, LinearExpr::from(x) - 3);
+    /// model.maximize(a);
     /// let response = model.solve();
     /// assert_eq!(response.status(), CpSolverStatus::Optimal);
-    /// assert!(x.solution_value(&response) as i64 != 1);
-    /// assert!(!x.solution_value(&response));
+    /// assert_eq!(13., response.objective_value);
+    /// assert_eq!(-10, x.solution_value(&response));
     /// ```
-    pub fn add_ne<T: Into<LinearExpr>, U: Into<LinearExpr>>(
+    pub fn add_abs_eq(
         &mut self,
-        lhs: T,
-        rhs: U,
+        target: impl Into<LinearExpr>,
+        expr: impl Into<LinearExpr>,
     ) -> Constraint {
-        self.add_linear_constraint(lhs.into() - rhs.into(), [(i64::MIN, -1), (1, i64::MAX)])
+        let expr = expr.into();
+        self.add_max_eq(target, [expr.clone(), -expr])
     }
 
     /// Adds a constraint that force the `target` to be equal to the
-    /// minimum of the given `exprs`.
+    /// element of `exprs` at position `index`, i.e. `target ==
+    /// exprs[index]`.
+    ///
+    /// The elements can be constants as well as any [`LinearExpr`].
     ///
     /// # Example
     ///
@@ -691,59 +2845,174 @@ impl CpModelBuilder {
     /// # use or_tools::builder::{CpModelBuilder, LinearExpr};
     /// # use or_tools::proto::CpSolverStatus;
     /// let mut model = CpModelBuilder::default();
+    /// let index = model.new_int_var([(0, 3)]);
+    /// let target = model.new_int_var([(-100, 100)]);
+    /// model.add_element(index, [7, 42, -3, 12], target);
+    /// model.maximize(target);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(42., response.objective_value);
+    /// assert_eq!(1, index.solution_value(&response));
+    ///
+    /// let mut model = CpModelBuilder::default();
     /// let x = model.new_int_var([(0, 10)]);
     /// let y = model.new_int_var([(5, 15)]);
-    /// let m = model.new_int_var([(-100, 100)]);
-    /// model.add_min_eq(m, [x, y]);
-    /// model.maximize(m);
+    /// let index = model.new_int_var([(0, 1)]);
+    /// let target = model.new_int_var([(-100, 100)]);
+    /// model.add_element(index, [x, y], target);
+    /// model.add_eq(index, 1);
+    /// model.minimize(target);
     /// let response = model.solve();
     /// assert_eq!(response.status(), CpSolverStatus::Optimal);
-    /// assert_eq!(10., response.objective_value);
-    /// assert_eq!(10, m.solution_value(&response));
+    /// assert_eq!(5., response.objective_value);
+    /// assert_eq!(5, y.solution_value(&response));
     /// ```
-    pub fn add_min_eq(
+    pub fn add_element(
         &mut self,
-        target: impl Into<LinearExpr>,
+        index: impl Into<LinearExpr>,
         exprs: impl IntoIterator<Item = impl Into<LinearExpr>>,
+        target: impl Into<LinearExpr>,
     ) -> Constraint {
-        self.add_cst(CstEnum::LinMax(proto::LinearArgumentProto {
-            target: Some((-target.into()).into()),
+        let mut overflow = false;
+        let cst = CstEnum::Element(proto::ElementConstraintProto {
+            linear_index: Some(expr_proto(&mut overflow, index)),
+            linear_target: Some(expr_proto(&mut overflow, target)),
             exprs: exprs
                 .into_iter()
-                .map(|expr| (-expr.into()).into())
+                .map(|e| expr_proto(&mut overflow, e))
                 .collect(),
+            ..Default::default()
+        });
+        self.add_checked_cst(cst, overflow)
+    }
+
+    /// Adds a constraint that force the `inverse` variables to be the
+    /// inverse permutation of the `direct` variables, i.e.
+    /// `direct[i] == j` iff `inverse[j] == i`.
+    ///
+    /// Both lists must have the same length `n`, and all the variables
+    /// take their values in `[0, n - 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let direct: Vec<_> = (0..3).map(|_| model.new_int_var([(0, 2)])).collect();
+    /// let inverse: Vec<_> = (0..3).map(|_| model.new_int_var([(0, 2)])).collect();
+    /// model.add_inverse(&direct, &inverse);
+    /// model.add_eq(direct[0], 2);
+    /// model.add_eq(direct[1], 0);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(0, inverse[2].solution_value(&response));
+    /// assert_eq!(1, inverse[0].solution_value(&response));
+    /// assert_eq!(2, inverse[1].solution_value(&response));
+    /// ```
+    pub fn add_inverse(&mut self, direct: &[IntVar], inverse: &[IntVar]) -> Constraint {
+        self.add_cst(CstEnum::Inverse(proto::InverseConstraintProto {
+            f_direct: direct.iter().map(|v| v.0).collect(),
+            f_inverse: inverse.iter().map(|v| v.0).collect(),
         }))
     }
 
-    /// Adds a constraint that force the `target` to be equal to the
-    /// maximum of the given `exprs`.
+    /// Adds an automaton constraint: the sequence of values taken by
+    /// `exprs` must be accepted by the given automaton.
+    ///
+    /// The automaton starts in `starting_state`, and each transition is
+    /// a `(tail, label, head)` triple meaning that reading the value
+    /// `label` in state `tail` moves to state `head`. After reading
+    /// all the values, the automaton must be in one of the
+    /// `final_states`.
+    ///
+    /// The transitions can be written declaratively with a regex-like
+    /// pattern using [`Automaton`][crate::automaton::Automaton].
     ///
     /// # Example
     ///
     /// ```
+    /// # use or_tools::automaton::Automaton;
     /// # use or_tools::builder::{CpModelBuilder, LinearExpr};
     /// # use or_tools::proto::CpSolverStatus;
     /// let mut model = CpModelBuilder::default();
-    /// let x = model.new_int_var([(0, 10)]);
-    /// let y = model.new_int_var([(5, 15)]);
-    /// let m = model.new_int_var([(-100, 100)]);
-    /// model.add_max_eq(m, [x, y]);
-    /// model.minimize(m);
+    /// // 0 is a day shift, 1 a night shift and 2 a day off
+    /// let shifts: Vec<_> = (0..7).map(|_| model.new_int_var([(0, 2)])).collect();
+    /// // no more than 3 night shifts in a row, followed by at least 2 days off
+    /// let rule: Automaton = "([0 2] | 1{1,3} 2 2)*".parse().unwrap();
+    /// model.add_automaton(
+    ///     shifts.iter().copied(),
+    ///     rule.starting_state,
+    ///     rule.final_states,
+    ///     rule.transitions,
+    /// );
+    /// for &shift in &shifts[..3] {
+    ///     model.add_eq(shift, 1);
+    /// }
     /// let response = model.solve();
     /// assert_eq!(response.status(), CpSolverStatus::Optimal);
-    /// assert_eq!(5., response.objective_value);
-    /// assert_eq!(5, m.solution_value(&response));
+    /// assert_eq!(2, shifts[3].solution_value(&response));
+    /// assert_eq!(2, shifts[4].solution_value(&response));
     /// ```
-    pub fn add_max_eq(
+    pub fn add_automaton(
         &mut self,
-        target: impl Into<LinearExpr>,
         exprs: impl IntoIterator<Item = impl Into<LinearExpr>>,
+        starting_state: i64,
+        final_states: impl IntoIterator<Item = i64>,
+        transitions: impl IntoIterator<Item = (i64, i64, i64)>,
     ) -> Constraint {
-        self.add_cst(CstEnum::LinMax(proto::LinearArgumentProto {
-            target: Some(target.into().into()),
-            exprs: exprs.into_iter().map(|e| e.into().into()).collect(),
-        }))
+        let mut overflow = false;
+        let mut automaton = proto::AutomatonConstraintProto {
+            starting_state,
+            final_states: final_states.into_iter().collect(),
+            exprs: exprs
+                .into_iter()
+                .map(|e| expr_proto(&mut overflow, e))
+                .collect(),
+            ..Default::default()
+        };
+        for (tail, label, head) in transitions {
+            automaton.transition_tail.push(tail);
+            automaton.transition_label.push(label);
+            automaton.transition_head.push(head);
+        }
+        self.add_checked_cst(CstEnum::Automaton(automaton), overflow)
+    }
+
+    /// Returns true if the expression can take the value zero. This is
+    /// exact for constants and expressions with a single variable, and
+    /// uses the bounds of the expression otherwise.
+    fn can_be_zero(&self, expr: &LinearExpr) -> bool {
+        match (expr.vars.as_slice(), expr.coeffs.as_slice()) {
+            ([], []) => expr.constant == 0,
+            (&[var], &[coeff]) if coeff != 0 => {
+                // the only value of the variable for which the expression is zero
+                let value = match expr.constant.checked_rem(coeff) {
+                    Some(0) => expr.constant.checked_div(coeff).and_then(i64::checked_neg),
+                    _ => None,
+                };
+                value.is_some_and(|value| self.var_domain(IntVar(var)).contains(value))
+            }
+            (vars, coeffs) => {
+                let (mut min, mut max) = (expr.constant, expr.constant);
+                for (&var, &coeff) in vars.iter().zip(coeffs) {
+                    let domain = self.var_domain(IntVar(var));
+                    let (Some(lo), Some(hi)) = (domain.min(), domain.max()) else {
+                        continue;
+                    };
+                    let (lo, hi) = if coeff >= 0 {
+                        (lo.saturating_mul(coeff), hi.saturating_mul(coeff))
+                    } else {
+                        (hi.saturating_mul(coeff), lo.saturating_mul(coeff))
+                    };
+                    min = min.saturating_add(lo);
+                    max = max.saturating_add(hi);
+                }
+                min <= 0 && 0 <= max
+            }
+        }
     }
+
     fn add_cst(&mut self, cst: CstEnum) -> Constraint {
         let index = self.proto.constraints.len();
         self.proto.constraints.push(proto::ConstraintProto {
@@ -753,7 +3022,109 @@ impl CpModelBuilder {
         Constraint(index)
     }
 
-    /// Add a solution hint.
+    /// Adds a constraint, which makes the model invalid as long as it
+    /// is enabled if one of its expressions overflowed.
+    fn add_checked_cst(&mut self, cst: CstEnum, overflow: bool) -> Constraint {
+        let constraint = self.add_cst(cst);
+        self.record_overflow(constraint, overflow);
+        constraint
+    }
+
+    /// Records that an expression added to the constraint overflowed.
+    fn record_overflow(&mut self, constraint: Constraint, overflow: bool) {
+        if overflow {
+            self.overflowed.insert(constraint.0);
+        }
+    }
+
+    /// Returns true if an expression of an enabled constraint, of the
+    /// objective or of a search strategy overflowed.
+    fn has_overflow(&self) -> bool {
+        self.objective_overflowed
+            || self.strategy_overflowed
+            || self
+                .overflowed
+                .iter()
+                .any(|index| !self.disabled.contains_key(index))
+    }
+
+    /// Removes the variables and constraints added after the model had
+    /// the given numbers of variables and constraints.
+    fn truncate(&mut self, num_vars: usize, num_constraints: usize) {
+        self.proto.variables.truncate(num_vars);
+        self.proto.constraints.truncate(num_constraints);
+        self.overflowed.retain(|&index| index < num_constraints);
+        self.constants
+            .retain(|_, var| usize::try_from(var.0).is_ok_and(|index| index < num_vars));
+        self.disabled.retain(|&index, _| index < num_constraints);
+    }
+
+    /// Adds a search strategy on the given expressions.
+    ///
+    /// The expressions are considered in order to create the search
+    /// decisions: `variable_selection` chooses the next expression to
+    /// branch on among the ones that are not fixed, and
+    /// `domain_reduction` the restriction of its domain to try first.
+    /// Strategies are applied in the order they are added.
+    ///
+    /// The strategies are only followed strictly with the
+    /// `FIXED_SEARCH` [search branching][proto::SatParameters::search_branching];
+    /// otherwise, they are a hint for some of the workers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, DomainReduction, VariableSelection};
+    /// # use or_tools::proto::{CpSolverStatus, SatParameters, sat_parameters::SearchBranching};
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(0, 10)]);
+    /// model.add_ge(x + y, 5);
+    /// model.add_decision_strategy(
+    ///     [x.into(), 10 - y],
+    ///     VariableSelection::ChooseFirst,
+    ///     DomainReduction::SelectMinValue,
+    /// );
+    ///
+    /// let mut params = SatParameters::default();
+    /// params.set_search_branching(SearchBranching::FixedSearch);
+    /// params.num_workers = Some(1);
+    /// let response = model.solve_with_parameters(&params);
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// // x is fixed to its minimum first, then 10 - y to its minimum
+    /// assert_eq!(0, x.solution_value(&response));
+    /// assert_eq!(10, y.solution_value(&response));
+    /// ```
+    pub fn add_decision_strategy(
+        &mut self,
+        exprs: impl IntoIterator<Item = impl Into<LinearExpr>>,
+        variable_selection: VariableSelection,
+        domain_reduction: DomainReduction,
+    ) {
+        let mut overflow = false;
+        let exprs = exprs
+            .into_iter()
+            .map(|e| expr_proto(&mut overflow, e))
+            .collect();
+        self.strategy_overflowed |= overflow;
+        self.proto
+            .search_strategy
+            .push(proto::DecisionStrategyProto {
+                exprs,
+                variable_selection_strategy:
+                    proto::decision_strategy_proto::VariableSelectionStrategy::from(
+                        variable_selection,
+                    )
+                    .into(),
+                domain_reduction_strategy:
+                    proto::decision_strategy_proto::DomainReductionStrategy::from(domain_reduction)
+                        .into(),
+                ..Default::default()
+            });
+    }
+
+    /// Add a solution hint. A variable that is already hinted gets the
+    /// new value.
     ///
     /// # Example
     ///
@@ -769,18 +3140,7 @@ impl CpModelBuilder {
     /// assert_eq!(response.status(), CpSolverStatus::Optimal);
     /// ```
     pub fn add_hint(&mut self, var: impl Into<IntVar>, value: i64) {
-        let var = var.into();
-        let hints = self
-            .proto
-            .solution_hint
-            .get_or_insert_with(Default::default);
-        if var.0 < 0 {
-            hints.vars.push(var.not().0);
-            hints.values.push(1 - value);
-        } else {
-            hints.vars.push(var.0);
-            hints.values.push(value);
-        }
+        self.set_hints([(var.into(), value)]);
     }
 
     /// Delete all solution hints.
@@ -803,10 +3163,224 @@ impl CpModelBuilder {
     /// ```
     pub fn del_hints(&mut self) {
         self.proto.solution_hint = None;
+        self.hint_positions.clear();
+    }
+
+    /// Adds the values of the solution of `response` as hints, to
+    /// warm start the next solve. `response` must come from a model
+    /// whose variables are the first variables of this one, e.g. a
+    /// previous version of this model: the variables created since then
+    /// are not hinted. The hints previously added for the same
+    /// variables are replaced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(0, 10)]);
+    /// model.add_le(x + y, 12);
+    /// model.maximize(x);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    ///
+    /// model.add_hints_from_response(&response);
+    /// assert!(model.hint_is_complete());
+    /// model.add_le(x, 8);
+    /// assert!(!model.hint_is_feasible());
+    /// ```
+    pub fn add_hints_from_response(&mut self, response: &proto::CpSolverResponse) {
+        self.set_hints(
+            (0..)
+                .map(IntVar)
+                .zip(response.solution.iter().copied())
+                .take(self.proto.variables.len()),
+        );
+    }
+
+    /// Adds the values of the given variables in the solution of
+    /// `response` as hints, like
+    /// [`CpModelBuilder::add_hints_from_response`] but only for a subset
+    /// of the variables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(0, 10)]);
+    /// model.maximize(x + y);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    ///
+    /// model.add_hints_from_response_for([x], &response);
+    /// assert!(!model.hint_is_complete());
+    /// ```
+    pub fn add_hints_from_response_for(
+        &mut self,
+        vars: impl IntoIterator<Item = impl Into<IntVar>>,
+        response: &proto::CpSolverResponse,
+    ) {
+        self.set_hints(vars.into_iter().map(|var| {
+            let var = var.into();
+            (var, var.solution_value(response))
+        }));
+    }
+
+    /// Adds the values of the solution of `response`, found for the
+    /// model `previous`, as hints for the variables of this model that
+    /// have the same name. This allows warm starting a model that was
+    /// rebuilt with its variables in a different order. The unnamed
+    /// variables of `previous` are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut previous = CpModelBuilder::default();
+    /// let x = previous.new_int_var_with_name([(0, 10)], "x");
+    /// previous.maximize(x);
+    /// let response = previous.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    ///
+    /// let mut model = CpModelBuilder::default();
+    /// let y = model.new_int_var_with_name([(0, 10)], "y");
+    /// let x = model.new_int_var_with_name([(0, 10)], "x");
+    /// model.add_hints_from_named_response(previous.proto(), &response);
+    /// let hint = model.proto().solution_hint.clone().unwrap_or_default();
+    /// assert_eq!((vec![1], vec![10]), (hint.vars, hint.values));
+    /// ```
+    pub fn add_hints_from_named_response(
+        &mut self,
+        previous: &proto::CpModelProto,
+        response: &proto::CpSolverResponse,
+    ) {
+        let mut vars = HashMap::new();
+        for view in self.variables() {
+            vars.entry(view.name).or_insert(view.var);
+        }
+        let hints: Vec<_> = previous
+            .variables
+            .iter()
+            .zip(&response.solution)
+            .filter(|(var, _)| !var.name.is_empty())
+            .filter_map(|(var, &value)| Some((*vars.get(var.name.as_str())?, value)))
+            .collect();
+        self.set_hints(hints);
+    }
+
+    /// Sets the hints of the given variables, replacing their previous
+    /// hints.
+    fn set_hints(&mut self, hints: impl IntoIterator<Item = (IntVar, i64)>) {
+        use std::collections::hash_map::Entry;
+
+        let hint = self
+            .proto
+            .solution_hint
+            .get_or_insert_with(Default::default);
+        let positions = &mut self.hint_positions;
+        // the hint of a loaded model, or one set as a whole, is not
+        // indexed yet
+        if positions.len() != hint.vars.len() {
+            positions.clear();
+            positions.extend(
+                hint.vars
+                    .iter()
+                    .enumerate()
+                    .map(|(position, &var)| (var, position)),
+            );
+        }
+        for (var, value) in hints {
+            let (var, value) = if var.0 < 0 {
+                (var.not().0, 1 - value)
+            } else {
+                (var.0, value)
+            };
+            match positions.entry(var) {
+                Entry::Occupied(entry) => {
+                    if let Some(hinted) = hint.values.get_mut(*entry.get()) {
+                        *hinted = value;
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(hint.vars.len());
+                    hint.vars.push(var);
+                    hint.values.push(value);
+                }
+            }
+        }
+    }
+
+    /// Returns the hinted value of each variable of the model, `None`
+    /// if a variable is not hinted.
+    fn hinted_solution(&self) -> Option<Vec<i64>> {
+        let mut solution = vec![None; self.proto.variables.len()];
+        if let Some(hint) = &self.proto.solution_hint {
+            for (&var, &value) in hint.vars.iter().zip(&hint.values) {
+                *solution.get_mut(usize::try_from(var).ok()?)? = Some(value);
+            }
+        }
+        solution.into_iter().collect()
+    }
+
+    /// Returns true if the solution hint gives a value to every
+    /// variable of the model.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_bool_var();
+    /// model.add_hint(x, 4);
+    /// assert!(!model.hint_is_complete());
+    /// model.add_hint(!y, 1);
+    /// assert!(model.hint_is_complete());
+    /// ```
+    #[must_use]
+    pub fn hint_is_complete(&self) -> bool {
+        self.hinted_solution().is_some()
+    }
+
+    /// Returns true if the solution hint is complete and is a feasible
+    /// solution of the model, as checked by
+    /// [`ffi::solution_is_feasible`]. This detects stale hints before
+    /// solving.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(0, 10)]);
+    /// model.add_le(x + y, 10);
+    /// model.add_hint(x, 4);
+    /// model.add_hint(y, 6);
+    /// assert!(model.hint_is_feasible());
+    /// model.add_ge(x, 5);
+    /// assert!(!model.hint_is_feasible());
+    /// ```
+    #[must_use]
+    pub fn hint_is_feasible(&self) -> bool {
+        self.hinted_solution()
+            .is_some_and(|solution| ffi::solution_is_feasible(&self.proto, &solution))
     }
 
     /// Sets the minimization objective.
     ///
+    /// This replaces any previous objective, including a floating
+    /// point one. The expression is
+    /// [canonicalized][LinearExpr::canonicalize] first: if it
+    /// overflows, the model becomes invalid, see
+    /// [`CpModelBuilder::validate_cp_model`].
+    ///
     /// # Example
     ///
     /// ```
@@ -821,7 +3395,10 @@ impl CpModelBuilder {
     /// assert_eq!(0., response.objective_value);
     /// ```
     pub fn minimize<T: Into<LinearExpr>>(&mut self, expr: T) {
-        let expr = expr.into();
+        let mut overflow = false;
+        let expr = canonicalized(&mut overflow, expr.into());
+        self.objective_overflowed = overflow;
+        self.proto.floating_point_objective = None;
         self.proto.objective = Some(proto::CpObjectiveProto {
             vars: expr.vars.into_vec(),
             coeffs: expr.coeffs.into_vec(),
@@ -835,7 +3412,123 @@ impl CpModelBuilder {
         });
     }
 
-    /// Sets the maximization objective.
+    /// Sets the maximization objective.
+    ///
+    /// This replaces any previous objective, including a floating
+    /// point one. The expression is
+    /// [canonicalized][LinearExpr::canonicalize] first: if it
+    /// overflows, the model becomes invalid, see
+    /// [`CpModelBuilder::validate_cp_model`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 100)]);
+    /// model.maximize(x);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(100, x.solution_value(&response));
+    /// assert_eq!(100., response.objective_value);
+    /// ```
+    pub fn maximize<T: Into<LinearExpr>>(&mut self, expr: T) {
+        let mut overflow = false;
+        let expr = canonicalized(&mut overflow, -expr.into());
+        self.objective_overflowed = overflow;
+        self.proto.floating_point_objective = None;
+        self.proto.objective = Some(proto::CpObjectiveProto {
+            vars: expr.vars.into_vec(),
+            coeffs: expr.coeffs.into_vec(),
+            offset: i64_to_f64(expr.constant),
+            scaling_factor: -1.,
+            domain: vec![],
+            scaling_was_exact: false,
+            integer_before_offset: 0,
+            integer_after_offset: 0,
+            integer_scaling_factor: 0,
+        });
+    }
+
+    /// Sets a minimization objective with floating point
+    /// coefficients, i.e. minimizes `sum(coeff * var) + offset`.
+    ///
+    /// The solver scales the objective to work with integers, so the
+    /// `objective_value` of the response may be slightly off. The
+    /// exact value of a solution can be computed with
+    /// [`CpModelBuilder::float_objective_value`]. This replaces any
+    /// previous objective.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(0, 10)]);
+    /// model.add_ge([(1, x), (1, y)], 5);
+    /// model.minimize_float(&[(0.25, x), (0.5, y)], 1.5);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(5, x.solution_value(&response));
+    /// assert!((model.float_objective_value(&response) - 2.75).abs() < 1e-9);
+    /// ```
+    pub fn minimize_float(&mut self, terms: &[(f64, IntVar)], offset: f64) {
+        self.set_float_objective(terms, offset, false);
+    }
+
+    /// Sets a maximization objective with floating point
+    /// coefficients, i.e. maximizes `sum(coeff * var) + offset`.
+    ///
+    /// See [`CpModelBuilder::minimize_float`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_bool_var();
+    /// let y = model.new_bool_var();
+    /// model.add_at_most_one([x, y]);
+    /// model.maximize_float(&[(0.7, x.into()), (0.3, y.into())], 0.);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(x.solution_value(&response));
+    /// assert!((model.float_objective_value(&response) - 0.7).abs() < 1e-9);
+    /// ```
+    pub fn maximize_float(&mut self, terms: &[(f64, IntVar)], offset: f64) {
+        self.set_float_objective(terms, offset, true);
+    }
+
+    fn set_float_objective(&mut self, terms: &[(f64, IntVar)], offset: f64, maximize: bool) {
+        self.objective_overflowed = false;
+        self.proto.objective = None;
+        let mut objective = proto::FloatObjectiveProto {
+            offset,
+            maximize,
+            ..Default::default()
+        };
+        for &(coeff, var) in terms {
+            // the objective only accepts positive references, and a
+            // negated literal `!b` stands for `1 - b`
+            if var.0 < 0 {
+                objective.vars.push(var.not().0);
+                objective.coeffs.push(-coeff);
+                objective.offset += coeff;
+            } else {
+                objective.vars.push(var.0);
+                objective.coeffs.push(coeff);
+            }
+        }
+        self.proto.floating_point_objective = Some(objective);
+    }
+
+    /// Returns the value of the floating point objective for the
+    /// solution of the response, or `0.` if the model has no floating
+    /// point objective.
     ///
     /// # Example
     ///
@@ -843,29 +3536,26 @@ impl CpModelBuilder {
     /// # use or_tools::builder::CpModelBuilder;
     /// # use or_tools::proto::CpSolverStatus;
     /// let mut model = CpModelBuilder::default();
-    /// let x = model.new_int_var([(0, 100)]);
-    /// model.maximize(x);
+    /// let x = model.new_int_var([(2, 4)]);
+    /// model.maximize_float(&[(1.5, x)], -1.);
     /// let response = model.solve();
     /// assert_eq!(response.status(), CpSolverStatus::Optimal);
-    /// assert_eq!(100, x.solution_value(&response));
-    /// assert_eq!(100., response.objective_value);
+    /// assert!((model.float_objective_value(&response) - 5.).abs() < 1e-9);
     /// ```
-    pub fn maximize<T: Into<LinearExpr>>(&mut self, expr: T) {
-        let mut expr = expr.into();
-        for coeff in &mut expr.coeffs {
-            *coeff *= -1;
-        }
-        self.proto.objective = Some(proto::CpObjectiveProto {
-            vars: expr.vars.into_vec(),
-            coeffs: expr.coeffs.into_vec(),
-            offset: -i64_to_f64(expr.constant),
-            scaling_factor: -1.,
-            domain: vec![],
-            scaling_was_exact: false,
-            integer_before_offset: 0,
-            integer_after_offset: 0,
-            integer_scaling_factor: 0,
-        });
+    #[must_use]
+    pub fn float_objective_value(&self, response: &proto::CpSolverResponse) -> f64 {
+        self.proto
+            .floating_point_objective
+            .as_ref()
+            .map_or(0., |objective| {
+                objective
+                    .vars
+                    .iter()
+                    .zip(&objective.coeffs)
+                    .map(|(&var, &coeff)| coeff * i64_to_f64(IntVar(var).solution_value(response)))
+                    .sum::<f64>()
+                    + objective.offset
+            })
     }
 
     /// Returns some statistics on the model.
@@ -883,11 +3573,51 @@ impl CpModelBuilder {
         ffi::cp_model_stats(self.proto())
     }
 
+    /// Returns some statistics on the model, computed in Rust, as a
+    /// structured [`ModelStats`].
+    ///
+    /// The constraints disabled with [`CpModelBuilder::disable`] are
+    /// only counted in [`ModelStats::num_disabled`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{ConstraintKind, CpModelBuilder};
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_bool_var();
+    /// let y = model.new_bool_var();
+    /// model.add_or([x, y]);
+    /// let both = model.add_and([x, y]);
+    /// model.disable(both);
+    /// let stats = model.model_stats();
+    /// assert_eq!(2, stats.num_booleans);
+    /// assert_eq!(Some(&1), stats.constraints.get(&ConstraintKind::BoolOr));
+    /// assert_eq!(None, stats.constraints.get(&ConstraintKind::Empty));
+    /// assert_eq!(1, stats.num_disabled);
+    /// ```
+    #[must_use]
+    pub fn model_stats(&self) -> ModelStats {
+        let enabled = (0..)
+            .zip(&self.proto.constraints)
+            .filter(|(index, _)| !self.disabled.contains_key(index))
+            .map(|(_, cst)| cst);
+        ModelStats {
+            num_disabled: self.disabled.len(),
+            ..ModelStats::with_constraints(&self.proto, enabled)
+        }
+    }
+
     /// Verifies that the given model satisfies all the properties
     /// described in the proto comments. Returns an empty string if it is
     /// the case, otherwise fails at the first error and returns a
     /// human-readable description of the issue.
     ///
+    /// The model is also invalid if a linear expression of an enabled
+    /// constraint, of the objective or of a search strategy overflowed,
+    /// either while it was built or while it was canonicalized. The
+    /// model becomes valid again once such a constraint is disabled or
+    /// such an objective is replaced.
+    ///
     /// # Example
     ///
     /// ```
@@ -897,14 +3627,43 @@ impl CpModelBuilder {
     /// let x = model.new_int_var([(0, -1)]);
     /// model.maximize(x);
     /// assert!(!model.validate_cp_model().is_empty());
+    ///
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// model.add_le([(i64::MAX, x), (i64::MAX, x)], 5);
+    /// assert_eq!(
+    ///     "integer overflow in a linear expression",
+    ///     model.validate_cp_model()
+    /// );
+    /// assert_eq!(model.solve().status(), CpSolverStatus::ModelInvalid);
     /// ```
     #[must_use]
     pub fn validate_cp_model(&self) -> String {
+        if self.has_overflow() {
+            return Error::Overflow.to_string();
+        }
         ffi::validate_cp_model(self.proto())
     }
 
+    /// Returns the response of a model that is invalid because a
+    /// linear expression overflowed, without calling the solver.
+    fn overflow_response(&self) -> Option<proto::CpSolverResponse> {
+        self.has_overflow().then(|| {
+            let mut response = proto::CpSolverResponse {
+                solution_info: Error::Overflow.to_string(),
+                ..Default::default()
+            };
+            response.set_status(proto::CpSolverStatus::ModelInvalid);
+            response
+        })
+    }
+
     /// Solves the model, and returns the corresponding [`proto::CpSolverResponse`].
     ///
+    /// If a linear expression given to the model overflowed, the model
+    /// is not solved and the status is
+    /// [`ModelInvalid`][proto::CpSolverStatus::ModelInvalid].
+    ///
     /// # Example
     ///
     /// ```
@@ -916,12 +3675,15 @@ impl CpModelBuilder {
     /// ```
     #[must_use]
     pub fn solve(&self) -> proto::CpSolverResponse {
-        ffi::solve(self.proto())
+        self.overflow_response()
+            .unwrap_or_else(|| ffi::solve(self.proto()))
     }
 
     /// Solves the model with the given
     /// [parameters][proto::SatParameters], and returns the
-    /// corresponding [`proto::CpSolverResponse`].
+    /// corresponding [`proto::CpSolverResponse`]. As for
+    /// [`CpModelBuilder::solve`], the model is not solved if a linear
+    /// expression overflowed.
     ///
     /// # Example
     ///
@@ -936,10 +3698,493 @@ impl CpModelBuilder {
     /// ```
     #[must_use]
     pub fn solve_with_parameters(&self, params: &proto::SatParameters) -> proto::CpSolverResponse {
-        ffi::solve_with_parameters(self.proto(), params)
+        self.overflow_response()
+            .unwrap_or_else(|| ffi::solve_with_parameters(self.proto(), params))
+    }
+
+    /// Solves the model for several objectives ordered by priority.
+    ///
+    /// The objectives are optimized one after the other. Once an
+    /// objective is optimized, its value is fixed by a constraint for
+    /// the next stages, and the solution is used as a hint for the
+    /// next stage. The builder is left unchanged, and any objective it
+    /// has is ignored.
+    ///
+    /// Solving stops early if a stage does not find a solution, in
+    /// which case the response of this stage is returned. With no
+    /// objective, the model is just solved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, LinearExpr, Sense};
+    /// # use or_tools::proto::{CpSolverStatus, SatParameters};
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(0, 10)]);
+    /// model.add_le([(1, x), (1, y)], 12);
+    /// let solution = model.solve_lexicographic(
+    ///     &[
+    ///         (LinearExpr::from(x), Sense::Maximize),
+    ///         (LinearExpr::from(y), Sense::Maximize),
+    ///     ],
+    ///     &SatParameters::default(),
+    /// );
+    /// assert_eq!(solution.response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(vec![10, 2], solution.objective_values);
+    /// assert_eq!(2, y.solution_value(&solution.response));
+    /// ```
+    #[must_use]
+    pub fn solve_lexicographic(
+        &self,
+        objectives: &[(LinearExpr, Sense)],
+        params: &proto::SatParameters,
+    ) -> LexicographicResponse {
+        self.solve_lexicographic_with_tolerance(
+            &objectives
+                .iter()
+                .map(|(expr, sense)| (expr.clone(), *sense, 0))
+                .collect::<Vec<_>>(),
+            params,
+        )
+    }
+
+    /// Solves the model for several objectives ordered by priority,
+    /// allowing each objective to degrade by the given tolerance in
+    /// the next stages.
+    ///
+    /// See [`CpModelBuilder::solve_lexicographic`]. With a tolerance
+    /// `t`, an objective minimized to `v` is constrained to be at most
+    /// `v + t` in the next stages, and an objective maximized to `v`
+    /// at least `v - t`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, LinearExpr, Sense};
+    /// # use or_tools::proto::{CpSolverStatus, SatParameters};
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(0, 10)]);
+    /// model.add_le([(1, x), (1, y)], 12);
+    /// let solution = model.solve_lexicographic_with_tolerance(
+    ///     &[
+    ///         (LinearExpr::from(x), Sense::Maximize, 3),
+    ///         (LinearExpr::from(y), Sense::Maximize, 0),
+    ///     ],
+    ///     &SatParameters::default(),
+    /// );
+    /// assert_eq!(solution.response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(vec![10, 5], solution.objective_values);
+    /// assert_eq!(7, x.solution_value(&solution.response));
+    /// ```
+    #[must_use]
+    pub fn solve_lexicographic_with_tolerance(
+        &self,
+        objectives: &[(LinearExpr, Sense, i64)],
+        params: &proto::SatParameters,
+    ) -> LexicographicResponse {
+        let mut model = self.clone();
+        model.proto.objective = None;
+        model.proto.floating_point_objective = None;
+        model.objective_overflowed = false;
+        let mut objective_values = Vec::with_capacity(objectives.len());
+        let mut response = None;
+        for (expr, sense, tolerance) in objectives {
+            match sense {
+                Sense::Minimize => model.minimize(expr.clone()),
+                Sense::Maximize => model.maximize(expr.clone()),
+            }
+            let stage = model.solve_with_parameters(params);
+            if !matches!(
+                stage.status(),
+                proto::CpSolverStatus::Optimal | proto::CpSolverStatus::Feasible
+            ) {
+                return LexicographicResponse {
+                    response: stage,
+                    objective_values,
+                };
+            }
+            let value = expr.solution_value(&stage);
+            match sense {
+                Sense::Minimize => model.add_le(expr.clone(), value.saturating_add(*tolerance)),
+                Sense::Maximize => model.add_ge(expr.clone(), value.saturating_sub(*tolerance)),
+            };
+            model.del_hints();
+            model.proto.solution_hint = Some(proto::PartialVariableAssignment {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                vars: (0..stage.solution.len() as i32).collect(),
+                values: stage.solution.clone(),
+            });
+            objective_values.push(value);
+            response = Some(stage);
+        }
+        LexicographicResponse {
+            response: response.unwrap_or_else(|| model.solve_with_parameters(params)),
+            objective_values,
+        }
+    }
+}
+
+/// How the next expression to branch on is chosen by
+/// [`CpModelBuilder::add_decision_strategy`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VariableSelection {
+    /// The first expression that is not fixed.
+    ChooseFirst,
+    /// The expression with the lowest minimum value.
+    ChooseLowestMin,
+    /// The expression with the highest maximum value.
+    ChooseHighestMax,
+    /// The expression with the smallest domain.
+    ChooseMinDomainSize,
+    /// The expression with the largest domain.
+    ChooseMaxDomainSize,
+}
+
+impl From<VariableSelection> for proto::decision_strategy_proto::VariableSelectionStrategy {
+    fn from(selection: VariableSelection) -> Self {
+        match selection {
+            VariableSelection::ChooseFirst => Self::ChooseFirst,
+            VariableSelection::ChooseLowestMin => Self::ChooseLowestMin,
+            VariableSelection::ChooseHighestMax => Self::ChooseHighestMax,
+            VariableSelection::ChooseMinDomainSize => Self::ChooseMinDomainSize,
+            VariableSelection::ChooseMaxDomainSize => Self::ChooseMaxDomainSize,
+        }
+    }
+}
+
+/// How the domain of the chosen expression is restricted by
+/// [`CpModelBuilder::add_decision_strategy`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DomainReduction {
+    /// Tries the minimum value first.
+    SelectMinValue,
+    /// Tries the maximum value first.
+    SelectMaxValue,
+    /// Tries the lower half of the domain first.
+    SelectLowerHalf,
+    /// Tries the upper half of the domain first.
+    SelectUpperHalf,
+    /// Tries the median value first.
+    SelectMedianValue,
+    /// Tries a random half of the domain first.
+    SelectRandomHalf,
+}
+
+impl From<DomainReduction> for proto::decision_strategy_proto::DomainReductionStrategy {
+    fn from(reduction: DomainReduction) -> Self {
+        match reduction {
+            DomainReduction::SelectMinValue => Self::SelectMinValue,
+            DomainReduction::SelectMaxValue => Self::SelectMaxValue,
+            DomainReduction::SelectLowerHalf => Self::SelectLowerHalf,
+            DomainReduction::SelectUpperHalf => Self::SelectUpperHalf,
+            DomainReduction::SelectMedianValue => Self::SelectMedianValue,
+            DomainReduction::SelectRandomHalf => Self::SelectRandomHalf,
+        }
+    }
+}
+
+/// The direction in which an objective is optimized.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Sense {
+    /// The objective is minimized.
+    Minimize,
+    /// The objective is maximized.
+    Maximize,
+}
+
+/// The result of [`CpModelBuilder::solve_lexicographic`].
+#[derive(Clone, Debug)]
+pub struct LexicographicResponse {
+    /// The response of the last solved stage.
+    pub response: proto::CpSolverResponse,
+    /// The value of the objective of each stage that found a
+    /// solution, in the order of the objectives.
+    pub objective_values: Vec<i64>,
+}
+
+/// A variable of the model, as returned by [`CpModelBuilder::variables`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VarView<'a> {
+    /// The variable.
+    pub var: IntVar,
+    /// The name of the variable, empty if not set.
+    pub name: &'a str,
+    /// The domain of the variable.
+    pub domain: Domain,
+}
+
+/// A constraint of the model, as returned by
+/// [`CpModelBuilder::constraints`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstraintView<'a> {
+    /// The constraint.
+    pub constraint: Constraint,
+    /// The name of the constraint, empty if not set.
+    pub name: &'a str,
+    /// The kind of the constraint.
+    pub kind: ConstraintKind,
+    /// False if the constraint is disabled by
+    /// [`CpModelBuilder::disable`]. The other fields then describe the
+    /// constraint as it will be once enabled again.
+    pub enabled: bool,
+    /// The literals that must all be true for the constraint to be
+    /// enforced.
+    pub enforcement_literals: Vec<BoolVar>,
+    /// The variables used by the constraint, sorted and without
+    /// duplicates. The enforcement literals are not included, nor the
+    /// variables of the intervals the constraint refers to. A literal
+    /// `!b` counts as the variable of `b`.
+    pub vars: Vec<IntVar>,
+    /// The underlying proto, without the name and the enforcement
+    /// literals set while the constraint is disabled.
+    pub proto: &'a proto::ConstraintProto,
+}
+
+/// The kind of a constraint, one per type of constraint of
+/// [`proto::ConstraintProto`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ConstraintKind {
+    /// A constraint without any type.
+    Empty,
+    /// See [`CpModelBuilder::add_or`].
+    BoolOr,
+    /// See [`CpModelBuilder::add_and`].
+    BoolAnd,
+    /// See [`CpModelBuilder::add_at_most_one`].
+    AtMostOne,
+    /// See [`CpModelBuilder::add_exactly_one`].
+    ExactlyOne,
+    /// See [`CpModelBuilder::add_xor`].
+    BoolXor,
+    /// See [`CpModelBuilder::add_division_eq`].
+    IntDiv,
+    /// See [`CpModelBuilder::add_modulo_eq`].
+    IntMod,
+    /// See [`CpModelBuilder::add_multiplication_eq`].
+    IntProd,
+    /// See [`CpModelBuilder::add_max_eq`] and
+    /// [`CpModelBuilder::add_min_eq`].
+    LinMax,
+    /// See [`CpModelBuilder::add_linear_constraint`].
+    Linear,
+    /// See [`CpModelBuilder::add_all_different`].
+    AllDiff,
+    /// See [`CpModelBuilder::add_element`].
+    Element,
+    /// See [`CpModelBuilder::add_circuit`].
+    Circuit,
+    /// See [`CpModelBuilder::add_multiple_circuit`].
+    Routes,
+    /// See [`CpModelBuilder::add_allowed_assignments`].
+    Table,
+    /// See [`CpModelBuilder::add_automaton`].
+    Automaton,
+    /// See [`CpModelBuilder::add_inverse`].
+    Inverse,
+    /// See [`CpModelBuilder::add_reservoir`].
+    Reservoir,
+    /// See [`CpModelBuilder::new_interval_var`].
+    Interval,
+    /// See [`CpModelBuilder::add_no_overlap`].
+    NoOverlap,
+    /// See [`CpModelBuilder::add_no_overlap_2d`].
+    NoOverlap2D,
+    /// See [`CpModelBuilder::add_cumulative`].
+    Cumulative,
+    /// A constraint only used to keep variables in the model.
+    Dummy,
+}
+
+impl ConstraintKind {
+    pub(crate) fn of(cst: &proto::ConstraintProto) -> Self {
+        match cst.constraint {
+            None => Self::Empty,
+            Some(CstEnum::BoolOr(_)) => Self::BoolOr,
+            Some(CstEnum::BoolAnd(_)) => Self::BoolAnd,
+            Some(CstEnum::AtMostOne(_)) => Self::AtMostOne,
+            Some(CstEnum::ExactlyOne(_)) => Self::ExactlyOne,
+            Some(CstEnum::BoolXor(_)) => Self::BoolXor,
+            Some(CstEnum::IntDiv(_)) => Self::IntDiv,
+            Some(CstEnum::IntMod(_)) => Self::IntMod,
+            Some(CstEnum::IntProd(_)) => Self::IntProd,
+            Some(CstEnum::LinMax(_)) => Self::LinMax,
+            Some(CstEnum::Linear(_)) => Self::Linear,
+            Some(CstEnum::AllDiff(_)) => Self::AllDiff,
+            Some(CstEnum::Element(_)) => Self::Element,
+            Some(CstEnum::Circuit(_)) => Self::Circuit,
+            Some(CstEnum::Routes(_)) => Self::Routes,
+            Some(CstEnum::Table(_)) => Self::Table,
+            Some(CstEnum::Automaton(_)) => Self::Automaton,
+            Some(CstEnum::Inverse(_)) => Self::Inverse,
+            Some(CstEnum::Reservoir(_)) => Self::Reservoir,
+            Some(CstEnum::Interval(_)) => Self::Interval,
+            Some(CstEnum::NoOverlap(_)) => Self::NoOverlap,
+            Some(CstEnum::NoOverlap2d(_)) => Self::NoOverlap2D,
+            Some(CstEnum::Cumulative(_)) => Self::Cumulative,
+            Some(CstEnum::DummyConstraint(_)) => Self::Dummy,
+        }
+    }
+
+    /// Returns the name of the constraint case in the C++ API.
+    pub(crate) fn case_name(self) -> &'static str {
+        match self {
+            Self::Empty => "kEmpty",
+            Self::BoolOr => "kBoolOr",
+            Self::BoolAnd => "kBoolAnd",
+            Self::AtMostOne => "kAtMostOne",
+            Self::ExactlyOne => "kExactlyOne",
+            Self::BoolXor => "kBoolXor",
+            Self::IntDiv => "kIntDiv",
+            Self::IntMod => "kIntMod",
+            Self::IntProd => "kIntProd",
+            Self::LinMax => "kLinMax",
+            Self::Linear => "kLinear",
+            Self::AllDiff => "kAllDiff",
+            Self::Element => "kElement",
+            Self::Circuit => "kCircuit",
+            Self::Routes => "kRoutes",
+            Self::Table => "kTable",
+            Self::Automaton => "kAutomaton",
+            Self::Inverse => "kInverse",
+            Self::Reservoir => "kReservoir",
+            Self::Interval => "kInterval",
+            Self::NoOverlap => "kNoOverlap",
+            Self::NoOverlap2D => "kNoOverlap2D",
+            Self::Cumulative => "kCumulative",
+            Self::Dummy => "kDummyConstraint",
+        }
+    }
+}
+
+/// Returns the variables used by a constraint, sorted and without
+/// duplicates, negated literals counting as their variable.
+fn constraint_vars(cst: &CstEnum) -> Vec<IntVar> {
+    fn exprs<'a>(
+        exprs: impl IntoIterator<Item = &'a proto::LinearExpressionProto>,
+    ) -> impl Iterator<Item = i32> {
+        exprs.into_iter().flat_map(|expr| expr.vars.iter().copied())
+    }
+    let refs: Vec<i32> = match cst {
+        CstEnum::BoolOr(cst)
+        | CstEnum::BoolAnd(cst)
+        | CstEnum::AtMostOne(cst)
+        | CstEnum::ExactlyOne(cst)
+        | CstEnum::BoolXor(cst) => cst.literals.clone(),
+        CstEnum::IntDiv(cst)
+        | CstEnum::IntMod(cst)
+        | CstEnum::IntProd(cst)
+        | CstEnum::LinMax(cst) => exprs(cst.target.iter().chain(&cst.exprs)).collect(),
+        CstEnum::Linear(cst) => cst.vars.clone(),
+        CstEnum::AllDiff(cst) => exprs(&cst.exprs).collect(),
+        CstEnum::Element(cst) => [cst.index, cst.target]
+            .into_iter()
+            .filter(|_| cst.linear_index.is_none() && cst.linear_target.is_none())
+            .chain(cst.vars.iter().copied())
+            .chain(exprs(
+                cst.linear_index
+                    .iter()
+                    .chain(&cst.linear_target)
+                    .chain(&cst.exprs),
+            ))
+            .collect(),
+        CstEnum::Circuit(cst) => cst.literals.clone(),
+        CstEnum::Routes(cst) => cst
+            .literals
+            .iter()
+            .copied()
+            .chain(exprs(cst.dimensions.iter().flat_map(|d| &d.exprs)))
+            .collect(),
+        CstEnum::Table(cst) => cst.vars.iter().copied().chain(exprs(&cst.exprs)).collect(),
+        CstEnum::Automaton(cst) => cst.vars.iter().copied().chain(exprs(&cst.exprs)).collect(),
+        CstEnum::Inverse(cst) => cst.f_direct.iter().chain(&cst.f_inverse).copied().collect(),
+        CstEnum::Reservoir(cst) => cst
+            .active_literals
+            .iter()
+            .copied()
+            .chain(exprs(cst.time_exprs.iter().chain(&cst.level_changes)))
+            .collect(),
+        CstEnum::Interval(cst) => {
+            exprs(cst.start.iter().chain(&cst.end).chain(&cst.size)).collect()
+        }
+        CstEnum::NoOverlap(_) | CstEnum::NoOverlap2d(_) => Vec::new(),
+        CstEnum::Cumulative(cst) => exprs(cst.capacity.iter().chain(&cst.demands)).collect(),
+        CstEnum::DummyConstraint(cst) => cst.vars.clone(),
+    };
+    let mut vars: Vec<IntVar> = refs
+        .into_iter()
+        .map(|r| IntVar(if r < 0 { -r - 1 } else { r }))
+        .collect();
+    vars.sort_unstable();
+    vars.dedup();
+    vars
+}
+
+/// Error returned by the fallible methods of [`CpModelBuilder`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A tuple of a table constraint does not have one value per
+    /// expression.
+    TupleArity {
+        /// Position of the tuple in the given tuples.
+        tuple: usize,
+        /// Number of expressions of the constraint.
+        expected: usize,
+        /// Number of values in the tuple.
+        actual: usize,
+    },
+    /// The denominator of a division, or the modulus of a modulo, can
+    /// be zero.
+    DivisionByZero,
+    /// An operation on a linear expression overflowed.
+    Overflow,
+    /// The constraint to reify is not a linear constraint, or is
+    /// already enforced.
+    NotReifiable,
+    /// The levels of a reservoir do not allow its initial level of
+    /// zero.
+    ReservoirLevels {
+        /// Minimum level of the reservoir.
+        min_level: i64,
+        /// Maximum level of the reservoir.
+        max_level: i64,
+    },
+    /// The model given to [`CpModelBuilder::from_proto`] is invalid,
+    /// with the description of the first issue.
+    InvalidModel(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::TupleArity {
+                tuple,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "tuple {tuple} has {actual} values, but the table has {expected} expressions"
+            ),
+            Error::DivisionByZero => write!(f, "the domain of the divisor contains zero"),
+            Error::Overflow => write!(f, "integer overflow in a linear expression"),
+            Error::NotReifiable => write!(f, "only unenforced linear constraints can be reified"),
+            Error::ReservoirLevels {
+                min_level,
+                max_level,
+            } => write!(
+                f,
+                "the reservoir levels [{min_level}, {max_level}] do not contain zero"
+            ),
+            Error::InvalidModel(message) => write!(f, "invalid model: {message}"),
+        }
     }
 }
 
+impl std::error::Error for Error {}
+
 /// Boolean variable identifier.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct BoolVar(i32);
@@ -976,6 +4221,44 @@ impl BoolVar {
             }
         }
     }
+
+    /// Returns the formula `self => rhs`, see [`BoolExpr`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let (a, b) = (model.new_bool_var(), model.new_bool_var());
+    /// model.add_bool_expr(a.implies(b) & a);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(b.solution_value(&response));
+    /// ```
+    #[must_use]
+    pub fn implies(self, rhs: impl Into<BoolExpr>) -> BoolExpr {
+        BoolExpr::from(self).implies(rhs)
+    }
+
+    /// Returns the formula `self <=> rhs`, see [`BoolExpr`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let (a, b) = (model.new_bool_var(), model.new_bool_var());
+    /// model.add_bool_expr(a.iff(!b) & b);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(!a.solution_value(&response));
+    /// ```
+    #[must_use]
+    pub fn iff(self, rhs: impl Into<BoolExpr>) -> BoolExpr {
+        BoolExpr::from(self).iff(rhs)
+    }
 }
 impl std::ops::Not for BoolVar {
     type Output = Self;
@@ -1049,9 +4332,207 @@ impl IntVar {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Constraint(usize);
 
+/// Cumulative constraint identifier, returned by
+/// [`CpModelBuilder::add_cumulative`].
+///
+/// It converts into a [`Constraint`], so it can be named or enforced
+/// like any other constraint.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CumulativeConstraint(Constraint);
+impl From<CumulativeConstraint> for Constraint {
+    fn from(cumulative: CumulativeConstraint) -> Constraint {
+        cumulative.0
+    }
+}
+
+/// 2D no-overlap constraint identifier, returned by
+/// [`CpModelBuilder::add_no_overlap_2d`].
+///
+/// It converts into a [`Constraint`], so it can be named or enforced
+/// like any other constraint.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NoOverlap2DConstraint(Constraint);
+impl From<NoOverlap2DConstraint> for Constraint {
+    fn from(no_overlap: NoOverlap2DConstraint) -> Constraint {
+        no_overlap.0
+    }
+}
+
+/// Reservoir constraint identifier, returned by
+/// [`CpModelBuilder::add_reservoir`].
+///
+/// It converts into a [`Constraint`], so it can be named or enforced
+/// like any other constraint.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReservoirConstraint(Constraint);
+impl From<ReservoirConstraint> for Constraint {
+    fn from(reservoir: ReservoirConstraint) -> Constraint {
+        reservoir.0
+    }
+}
+
+/// Circuit constraint identifier, returned by
+/// [`CpModelBuilder::add_circuit`].
+///
+/// It converts into a [`Constraint`], so it can be named or enforced
+/// like any other constraint.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CircuitConstraint(Constraint);
+impl From<CircuitConstraint> for Constraint {
+    fn from(circuit: CircuitConstraint) -> Constraint {
+        circuit.0
+    }
+}
+
+/// Multiple circuit constraint identifier, returned by
+/// [`CpModelBuilder::add_multiple_circuit`].
+///
+/// It converts into a [`Constraint`], so it can be named or enforced
+/// like any other constraint.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MultipleCircuitConstraint(Constraint);
+impl From<MultipleCircuitConstraint> for Constraint {
+    fn from(routes: MultipleCircuitConstraint) -> Constraint {
+        routes.0
+    }
+}
+
 /// Interval variable identifier.
+///
+/// Like the other identifiers, it is only an index in the model: the
+/// start, size and end of an interval are linear expressions stored in
+/// the model, so reading them from a solution, e.g. with
+/// [`IntervalVar::solution_start`], also takes the model.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct IntervalVar(i32);
+impl IntervalVar {
+    /// Returns the constraint defining the interval, e.g. to name it
+    /// with [`CpModelBuilder::set_constraint_name`].
+    ///
+    /// Enforcement literals added to this constraint make the interval
+    /// optional, as with [`CpModelBuilder::new_optional_interval_var`].
+    /// The constraint must not be disabled with
+    /// [`CpModelBuilder::disable`] while other constraints use the
+    /// interval, as these constraints would become invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// let start = model.new_int_var([(0, 10)]);
+    /// let interval = model.new_fixed_size_interval_var(start, 3);
+    /// model.set_constraint_name(interval.constraint(), "task");
+    /// assert_eq!("task", model.constraint_name(interval.constraint()));
+    /// ```
+    #[must_use]
+    pub fn constraint(self) -> Constraint {
+        #[allow(clippy::cast_sign_loss)]
+        Constraint(self.0 as usize)
+    }
+
+    /// Gets the start of the interval from a solution.
+    ///
+    /// The model and the solution must be the ones the interval
+    /// comes from, and a solution must be present in the response.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let start = model.new_int_var([(2, 10)]);
+    /// let interval = model.new_fixed_size_interval_var(start, 3);
+    /// model.minimize(start);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(2, interval.solution_start(&model, &response));
+    /// ```
+    #[track_caller]
+    #[must_use]
+    pub fn solution_start(self, model: &CpModelBuilder, response: &proto::CpSolverResponse) -> i64 {
+        model.interval_start(self).solution_value(response)
+    }
+
+    /// Gets the size of the interval from a solution.
+    ///
+    /// The model and the solution must be the ones the interval
+    /// comes from, and a solution must be present in the response.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let start = model.new_int_var([(0, 10)]);
+    /// let size = model.new_int_var([(1, 5)]);
+    /// let end = model.new_int_var([(0, 10)]);
+    /// let interval = model.new_interval_var(start, size, end);
+    /// model.maximize(size);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(5, interval.solution_size(&model, &response));
+    /// ```
+    #[track_caller]
+    #[must_use]
+    pub fn solution_size(self, model: &CpModelBuilder, response: &proto::CpSolverResponse) -> i64 {
+        model.interval_size(self).solution_value(response)
+    }
+
+    /// Gets the end of the interval from a solution.
+    ///
+    /// The model and the solution must be the ones the interval
+    /// comes from, and a solution must be present in the response.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let start = model.new_int_var([(0, 10)]);
+    /// let interval = model.new_fixed_size_interval_var(start, 3);
+    /// model.maximize(start);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(13, interval.solution_end(&model, &response));
+    /// ```
+    #[track_caller]
+    #[must_use]
+    pub fn solution_end(self, model: &CpModelBuilder, response: &proto::CpSolverResponse) -> i64 {
+        model.interval_end(self).solution_value(response)
+    }
+
+    /// Returns true if the interval is present in a solution.
+    ///
+    /// The model and the solution must be the ones the interval
+    /// comes from, and a solution must be present in the response.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let start = model.new_int_var([(0, 10)]);
+    /// let end = model.new_int_var([(0, 10)]);
+    /// let present = model.new_bool_var();
+    /// let interval = model.new_optional_interval_var(start, 2, end, present);
+    /// model.add_and([!present]);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(!interval.is_present(&model, &response));
+    /// ```
+    #[track_caller]
+    #[must_use]
+    pub fn is_present(self, model: &CpModelBuilder, response: &proto::CpSolverResponse) -> bool {
+        model
+            .interval_presence(self)
+            .is_none_or(|presence| presence.solution_value(response))
+    }
+}
 
 /// A linear expression, used in several places in the
 /// [builder][CpModelBuilder].
@@ -1097,12 +4578,303 @@ pub struct IntervalVar(i32);
 /// // meaning summing the elements
 /// model.maximize(vars.iter().copied().collect::<LinearExpr>()); // means sum(vars)
 /// expr.extend(vars.iter().map(|&v| (2, v))); // means expr += sum_vars(2 * v)
+///
+/// // variables and constants also compose with the usual operators
+/// model.maximize(2 * x1 - 3 * y1 + x2 * 4 - y2 + 5);
+/// model.maximize(-x1 + (10 - x2));
 /// ```
 #[derive(Clone, Default, Debug)]
 pub struct LinearExpr {
     vars: SmallVec<[i32; 4]>,
     coeffs: SmallVec<[i64; 2]>,
     constant: i64,
+    /// Whether an operation overflowed, in which case the values
+    /// have been saturated.
+    overflow: bool,
+}
+
+/// Returns the canonicalized expression, recording in `overflow`
+/// whether it overflowed, in which case it is returned as is.
+fn canonicalized(overflow: &mut bool, mut expr: LinearExpr) -> LinearExpr {
+    *overflow |= expr.canonicalize().is_err();
+    expr
+}
+
+/// Converts the expression to a proto, recording in `overflow` whether
+/// it overflowed, in which case its values are saturated.
+fn expr_proto(overflow: &mut bool, expr: impl Into<LinearExpr>) -> proto::LinearExpressionProto {
+    let expr = expr.into();
+    *overflow |= expr.overflow;
+    expr.into()
+}
+
+/// Returns the result of a checked operation, or records the overflow
+/// and returns the saturated result.
+fn checked_or_saturated(overflow: &mut bool, checked: Option<i64>, saturated: i64) -> i64 {
+    checked.unwrap_or_else(|| {
+        *overflow = true;
+        saturated
+    })
+}
+
+impl LinearExpr {
+    /// Returns the sum of the given expressions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, LinearExpr};
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let vars: Vec<_> = (0..3).map(|_| model.new_int_var([(0, 5)])).collect();
+    /// model.add_eq(LinearExpr::sum(vars.iter().copied()), 12);
+    /// // same as
+    /// model.add_eq(vars.iter().copied().sum::<LinearExpr>(), 12);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(12, vars.iter().map(|v| v.solution_value(&response)).sum::<i64>());
+    /// ```
+    pub fn sum<T: Into<LinearExpr>>(exprs: impl IntoIterator<Item = T>) -> Self {
+        exprs.into_iter().collect()
+    }
+
+    /// Returns the sum of the given variables multiplied by the
+    /// corresponding coefficients. Extra variables or coefficients
+    /// are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, LinearExpr};
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let vars: Vec<_> = (0..3).map(|_| model.new_bool_var()).collect();
+    /// let weights = [3, 5, 7];
+    /// model.add_le(LinearExpr::weighted_sum(vars.iter().copied(), weights), 10);
+    /// model.maximize(LinearExpr::sum(vars.iter().copied()));
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!(vars[0].solution_value(&response));
+    /// ```
+    pub fn weighted_sum<V: Into<IntVar>>(
+        vars: impl IntoIterator<Item = V>,
+        coeffs: impl IntoIterator<Item = i64>,
+    ) -> Self {
+        vars.into_iter()
+            .zip(coeffs)
+            .map(|(var, coeff)| (coeff, var))
+            .collect()
+    }
+
+    /// Iterates over the `(variable, coefficient)` terms of the
+    /// expression.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let b = model.new_bool_var();
+    /// let expr = 2 * x + 3 * !b;
+    /// assert_eq!(vec![(x, 2), (b.into(), -3)], expr.terms().collect::<Vec<_>>());
+    /// assert_eq!(3, expr.constant());
+    /// ```
+    pub fn terms(&self) -> impl Iterator<Item = (IntVar, i64)> + '_ {
+        self.vars
+            .iter()
+            .zip(&self.coeffs)
+            .map(|(&var, &coeff)| (IntVar(var), coeff))
+    }
+
+    /// Returns the constant of the expression.
+    #[must_use]
+    pub fn constant(&self) -> i64 {
+        self.constant
+    }
+
+    /// Merges the terms of the same variable, and removes the terms
+    /// with a zero coefficient. The terms are then sorted by variable.
+    ///
+    /// The builder methods emitting linear protos canonicalize their
+    /// expressions, so there is usually no need to call this
+    /// directly.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`], leaving the expression unchanged,
+    /// if the expression overflowed when it was built, or if merging
+    /// the coefficients overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, Error, LinearExpr};
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_int_var([(0, 10)]);
+    /// let mut expr = x + 2 * y - x + 3 * x - 2 * y;
+    /// expr.canonicalize()?;
+    /// assert_eq!(vec![(x, 3)], expr.terms().collect::<Vec<_>>());
+    ///
+    /// let mut overflow = LinearExpr::from((i64::MAX, x)) + x;
+    /// assert_eq!(Err(Error::Overflow), overflow.canonicalize());
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn canonicalize(&mut self) -> Result<(), Error> {
+        if self.overflow {
+            return Err(Error::Overflow);
+        }
+        let mut terms = std::collections::BTreeMap::new();
+        for (&var, &coeff) in self.vars.iter().zip(&self.coeffs) {
+            let sum: &mut i64 = terms.entry(var).or_default();
+            *sum = sum.checked_add(coeff).ok_or(Error::Overflow)?;
+        }
+        terms.retain(|_, coeff| *coeff != 0);
+        self.vars = terms.keys().copied().collect();
+        self.coeffs = terms.into_values().collect();
+        Ok(())
+    }
+
+    /// Adds two expressions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if the sum, or one of the
+    /// operands, overflowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, Error, LinearExpr};
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// assert!(LinearExpr::from(x).checked_add(5).is_ok());
+    /// let big = LinearExpr::from(x) + i64::MAX;
+    /// assert_eq!(Some(Error::Overflow), big.checked_add(1).err());
+    /// ```
+    pub fn checked_add(self, rhs: impl Into<LinearExpr>) -> Result<Self, Error> {
+        (self + rhs).checked()
+    }
+
+    /// Subtracts two expressions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if the difference, or one of the
+    /// operands, overflowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, Error, LinearExpr};
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// assert!(LinearExpr::from(x).checked_sub(x).is_ok());
+    /// let small = LinearExpr::from(x) + i64::MIN;
+    /// assert_eq!(Some(Error::Overflow), small.checked_sub(1).err());
+    /// ```
+    pub fn checked_sub(self, rhs: impl Into<LinearExpr>) -> Result<Self, Error> {
+        (self - rhs).checked()
+    }
+
+    /// Multiplies an expression by a constant.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if the product, or the expression,
+    /// overflowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, Error, LinearExpr};
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// assert!(LinearExpr::from(x).checked_mul(1 << 32).is_ok());
+    /// let big = LinearExpr::from((1 << 32, x));
+    /// assert_eq!(Some(Error::Overflow), big.checked_mul(1 << 32).err());
+    /// ```
+    pub fn checked_mul(self, rhs: i64) -> Result<Self, Error> {
+        (self * rhs).checked()
+    }
+
+    fn checked(self) -> Result<Self, Error> {
+        if self.overflow {
+            Err(Error::Overflow)
+        } else {
+            Ok(self)
+        }
+    }
+
+    /// Gets the value of the expression from a solution.
+    ///
+    /// The solution must come from the same model as the variables of
+    /// the expression, and a solution must be present in the response.
+    /// If the value does not fit in an `i64`, it is saturated to
+    /// `i64::MIN` or `i64::MAX`: use
+    /// [`LinearExpr::checked_solution_value`] to detect it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, LinearExpr};
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// let y = model.new_bool_var();
+    /// let expr = LinearExpr::from([(2, x)]) - y + 5;
+    /// model.maximize(expr.clone());
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert_eq!(25, expr.solution_value(&response));
+    /// ```
+    #[track_caller]
+    #[must_use]
+    pub fn solution_value(&self, response: &proto::CpSolverResponse) -> i64 {
+        let value = self.wide_solution_value(response);
+        i64::try_from(value).unwrap_or(if value < 0 { i64::MIN } else { i64::MAX })
+    }
+
+    /// Gets the value of the expression from a solution, like
+    /// [`LinearExpr::solution_value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if the value does not fit in an
+    /// `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::{CpModelBuilder, Error, LinearExpr};
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let x = model.new_int_var([(0, 10)]);
+    /// model.maximize(x);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// let big = LinearExpr::from((i64::MAX / 4, x));
+    /// assert_eq!(Err(Error::Overflow), big.checked_solution_value(&response));
+    /// assert_eq!(i64::MAX, big.solution_value(&response));
+    /// ```
+    #[track_caller]
+    pub fn checked_solution_value(&self, response: &proto::CpSolverResponse) -> Result<i64, Error> {
+        i64::try_from(self.wide_solution_value(response)).map_err(|_| Error::Overflow)
+    }
+
+    /// Returns the value of the expression computed with `i128`, which
+    /// is exact unless there are many terms close to the `i64` bounds.
+    #[track_caller]
+    fn wide_solution_value(&self, response: &proto::CpSolverResponse) -> i128 {
+        self.vars
+            .iter()
+            .zip(&self.coeffs)
+            .map(|(&var, &coeff)| {
+                i128::from(coeff) * i128::from(IntVar(var).solution_value(response))
+            })
+            .fold(i128::from(self.constant), i128::saturating_add)
+    }
 }
 
 impl<E: Into<LinearExpr>> std::ops::AddAssign<E> for LinearExpr {
@@ -1113,16 +4885,25 @@ impl<E: Into<LinearExpr>> std::ops::AddAssign<E> for LinearExpr {
         }
         self.vars.extend_from_slice(&rhs.vars);
         self.coeffs.extend_from_slice(&rhs.coeffs);
-        self.constant += rhs.constant;
+        self.overflow |= rhs.overflow;
+        self.constant = checked_or_saturated(
+            &mut self.overflow,
+            self.constant.checked_add(rhs.constant),
+            self.constant.saturating_add(rhs.constant),
+        );
     }
 }
 impl std::ops::Neg for LinearExpr {
     type Output = LinearExpr;
     fn neg(mut self) -> Self::Output {
         for c in &mut self.coeffs {
-            *c = -*c;
+            *c = checked_or_saturated(&mut self.overflow, c.checked_neg(), c.saturating_neg());
         }
-        self.constant = -self.constant;
+        self.constant = checked_or_saturated(
+            &mut self.overflow,
+            self.constant.checked_neg(),
+            self.constant.saturating_neg(),
+        );
         self
     }
 }
@@ -1150,8 +4931,12 @@ impl<V: Into<IntVar>> From<(i64, V)> for LinearExpr {
         let var = var.into();
         if var.0 < 0 {
             res.vars.push(var.not().0);
-            res.coeffs.push(-coeff);
-            res.constant += coeff;
+            res.coeffs.push(checked_or_saturated(
+                &mut res.overflow,
+                coeff.checked_neg(),
+                coeff.saturating_neg(),
+            ));
+            res.constant = coeff;
         } else {
             res.vars.push(var.0);
             res.coeffs.push(coeff);
@@ -1189,13 +4974,113 @@ impl std::ops::Mul<i64> for LinearExpr {
     type Output = LinearExpr;
     fn mul(mut self, rhs: i64) -> Self::Output {
         for c in &mut self.coeffs {
-            *c *= rhs;
+            *c = checked_or_saturated(
+                &mut self.overflow,
+                c.checked_mul(rhs),
+                c.saturating_mul(rhs),
+            );
         }
-        self.constant *= rhs;
+        self.constant = checked_or_saturated(
+            &mut self.overflow,
+            self.constant.checked_mul(rhs),
+            self.constant.saturating_mul(rhs),
+        );
         self
     }
 }
 
+impl std::ops::Mul<LinearExpr> for i64 {
+    type Output = LinearExpr;
+    fn mul(self, rhs: LinearExpr) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl std::ops::Add<LinearExpr> for i64 {
+    type Output = LinearExpr;
+    fn add(self, rhs: LinearExpr) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl std::ops::Sub<LinearExpr> for i64 {
+    type Output = LinearExpr;
+    fn sub(self, rhs: LinearExpr) -> Self::Output {
+        -rhs + self
+    }
+}
+
+impl std::ops::Mul<i64> for IntVar {
+    type Output = LinearExpr;
+    fn mul(self, rhs: i64) -> Self::Output {
+        LinearExpr::from((rhs, self))
+    }
+}
+
+/// Implements the arithmetic operators of a variable type, giving
+/// [`LinearExpr`]s.
+macro_rules! impl_var_ops {
+    ($var:ty) => {
+        impl<T: Into<LinearExpr>> std::ops::Add<T> for $var {
+            type Output = LinearExpr;
+            fn add(self, rhs: T) -> Self::Output {
+                LinearExpr::from(self) + rhs
+            }
+        }
+
+        impl<T: Into<LinearExpr>> std::ops::Sub<T> for $var {
+            type Output = LinearExpr;
+            fn sub(self, rhs: T) -> Self::Output {
+                LinearExpr::from(self) - rhs
+            }
+        }
+
+        impl std::ops::Neg for $var {
+            type Output = LinearExpr;
+            fn neg(self) -> Self::Output {
+                LinearExpr::from((-1, self))
+            }
+        }
+
+        impl std::ops::Mul<$var> for i64 {
+            type Output = LinearExpr;
+            fn mul(self, rhs: $var) -> Self::Output {
+                LinearExpr::from((self, rhs))
+            }
+        }
+
+        impl std::ops::Add<$var> for i64 {
+            type Output = LinearExpr;
+            fn add(self, rhs: $var) -> Self::Output {
+                LinearExpr::from(rhs) + self
+            }
+        }
+
+        impl std::ops::Sub<$var> for i64 {
+            type Output = LinearExpr;
+            fn sub(self, rhs: $var) -> Self::Output {
+                -rhs + self
+            }
+        }
+    };
+}
+impl_var_ops!(IntVar);
+impl_var_ops!(BoolVar);
+
+impl From<proto::LinearExpressionProto> for LinearExpr {
+    fn from(expr: proto::LinearExpressionProto) -> Self {
+        LinearExpr {
+            vars: expr.vars.into(),
+            coeffs: expr.coeffs.into(),
+            constant: expr.offset,
+            overflow: false,
+        }
+    }
+}
+
+/// The values of an expression that overflowed are saturated. The
+/// [`CpModelBuilder`] records such overflows to report the model as
+/// invalid, see [`CpModelBuilder::validate_cp_model`].
 impl From<LinearExpr> for proto::LinearExpressionProto {
     fn from(expr: LinearExpr) -> Self {
         proto::LinearExpressionProto {
@@ -1220,3 +5105,222 @@ impl<T: Into<LinearExpr>> std::iter::FromIterator<T> for LinearExpr {
         res
     }
 }
+impl<T: Into<LinearExpr>> std::iter::Sum<T> for LinearExpr {
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.collect()
+    }
+}
+
+/// A Boolean formula over [`BoolVar`]s, used by
+/// [`CpModelBuilder::add_bool_expr`] and
+/// [`CpModelBuilder::new_bool_expr_var`].
+///
+/// Formulas are built from literals with the `&`, `|` and `!`
+/// operators, and with the [`BoolExpr::implies`] and
+/// [`BoolExpr::iff`] methods.
+///
+/// # Example
+///
+/// ```
+/// # use or_tools::builder::{BoolExpr, CpModelBuilder};
+/// let mut model = CpModelBuilder::default();
+/// let (a, b, c) = (model.new_bool_var(), model.new_bool_var(), model.new_bool_var());
+/// let expr = (a & !b) | c.implies(a);
+/// assert_eq!(
+///     BoolExpr::Or(vec![
+///         BoolExpr::And(vec![a.into(), (!b).into()]),
+///         BoolExpr::Implies(Box::new(c.into()), Box::new(a.into())),
+///     ]),
+///     expr
+/// );
+/// let rule = BoolExpr::all([a, b, c]).iff(BoolExpr::any([a, b]) & true);
+/// model.add_bool_expr(rule);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BoolExpr {
+    /// A literal.
+    Literal(BoolVar),
+    /// A constant.
+    Constant(bool),
+    /// The negation of a formula.
+    Not(Box<BoolExpr>),
+    /// The conjunction of formulas, true if empty.
+    And(Vec<BoolExpr>),
+    /// The disjunction of formulas, false if empty.
+    Or(Vec<BoolExpr>),
+    /// The implication of the second formula by the first one.
+    Implies(Box<BoolExpr>, Box<BoolExpr>),
+    /// The equivalence of two formulas.
+    Iff(Box<BoolExpr>, Box<BoolExpr>),
+}
+
+impl BoolExpr {
+    /// Returns the conjunction of the given formulas.
+    pub fn all<T: Into<BoolExpr>>(exprs: impl IntoIterator<Item = T>) -> Self {
+        BoolExpr::And(exprs.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the disjunction of the given formulas.
+    pub fn any<T: Into<BoolExpr>>(exprs: impl IntoIterator<Item = T>) -> Self {
+        BoolExpr::Or(exprs.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the formula `self => rhs`.
+    #[must_use]
+    pub fn implies(self, rhs: impl Into<BoolExpr>) -> Self {
+        BoolExpr::Implies(Box::new(self), Box::new(rhs.into()))
+    }
+
+    /// Returns the formula `self <=> rhs`.
+    #[must_use]
+    pub fn iff(self, rhs: impl Into<BoolExpr>) -> Self {
+        BoolExpr::Iff(Box::new(self), Box::new(rhs.into()))
+    }
+
+    /// Gets the value of the formula from a solution.
+    ///
+    /// The solution must come from the same model as the literals of
+    /// the formula, and a solution must be present in the response.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::builder::CpModelBuilder;
+    /// # use or_tools::proto::CpSolverStatus;
+    /// let mut model = CpModelBuilder::default();
+    /// let (a, b) = (model.new_bool_var(), model.new_bool_var());
+    /// model.add_and([a, !b]);
+    /// let response = model.solve();
+    /// assert_eq!(response.status(), CpSolverStatus::Optimal);
+    /// assert!((a | b).solution_value(&response));
+    /// assert!(!a.iff(b).solution_value(&response));
+    /// ```
+    #[track_caller]
+    #[must_use]
+    pub fn solution_value(&self, response: &proto::CpSolverResponse) -> bool {
+        match self {
+            BoolExpr::Literal(literal) => literal.solution_value(response),
+            BoolExpr::Constant(value) => *value,
+            BoolExpr::Not(expr) => !expr.solution_value(response),
+            BoolExpr::And(exprs) => exprs.iter().all(|e| e.solution_value(response)),
+            BoolExpr::Or(exprs) => exprs.iter().any(|e| e.solution_value(response)),
+            BoolExpr::Implies(lhs, rhs) => {
+                !lhs.solution_value(response) || rhs.solution_value(response)
+            }
+            BoolExpr::Iff(lhs, rhs) => lhs.solution_value(response) == rhs.solution_value(response),
+        }
+    }
+}
+
+impl From<BoolVar> for BoolExpr {
+    fn from(literal: BoolVar) -> Self {
+        BoolExpr::Literal(literal)
+    }
+}
+
+impl From<bool> for BoolExpr {
+    fn from(value: bool) -> Self {
+        BoolExpr::Constant(value)
+    }
+}
+
+impl<T: Into<BoolExpr>> std::ops::BitAnd<T> for BoolExpr {
+    type Output = BoolExpr;
+    fn bitand(self, rhs: T) -> Self::Output {
+        match self {
+            BoolExpr::And(mut exprs) => {
+                exprs.push(rhs.into());
+                BoolExpr::And(exprs)
+            }
+            lhs => BoolExpr::And(vec![lhs, rhs.into()]),
+        }
+    }
+}
+
+impl<T: Into<BoolExpr>> std::ops::BitOr<T> for BoolExpr {
+    type Output = BoolExpr;
+    fn bitor(self, rhs: T) -> Self::Output {
+        match self {
+            BoolExpr::Or(mut exprs) => {
+                exprs.push(rhs.into());
+                BoolExpr::Or(exprs)
+            }
+            lhs => BoolExpr::Or(vec![lhs, rhs.into()]),
+        }
+    }
+}
+
+impl std::ops::Not for BoolExpr {
+    type Output = BoolExpr;
+    fn not(self) -> Self::Output {
+        match self {
+            BoolExpr::Literal(literal) => BoolExpr::Literal(!literal),
+            BoolExpr::Constant(value) => BoolExpr::Constant(!value),
+            BoolExpr::Not(expr) => *expr,
+            expr => BoolExpr::Not(Box::new(expr)),
+        }
+    }
+}
+
+impl<T: Into<BoolExpr>> std::ops::BitAnd<T> for BoolVar {
+    type Output = BoolExpr;
+    fn bitand(self, rhs: T) -> Self::Output {
+        BoolExpr::from(self) & rhs
+    }
+}
+
+impl<T: Into<BoolExpr>> std::ops::BitOr<T> for BoolVar {
+    type Output = BoolExpr;
+    fn bitor(self, rhs: T) -> Self::Output {
+        BoolExpr::from(self) | rhs
+    }
+}
+
+/// A Boolean formula in negation normal form, where the negations
+/// only apply to literals.
+enum Nnf {
+    Literal(BoolVar),
+    Constant(bool),
+    And(Vec<Nnf>),
+    Or(Vec<Nnf>),
+}
+
+impl Nnf {
+    /// Returns the conjunction of the formulas, flattening nested
+    /// conjunctions and folding constants.
+    fn and(children: Vec<Nnf>) -> Nnf {
+        let mut flat = Vec::with_capacity(children.len());
+        for child in children {
+            match child {
+                Nnf::Constant(true) => {}
+                Nnf::Constant(false) => return Nnf::Constant(false),
+                Nnf::And(grandchildren) => flat.extend(grandchildren),
+                child => flat.push(child),
+            }
+        }
+        match flat.len() {
+            0 => Nnf::Constant(true),
+            1 => flat.pop().unwrap_or(Nnf::Constant(true)),
+            _ => Nnf::And(flat),
+        }
+    }
+
+    /// Returns the disjunction of the formulas, flattening nested
+    /// disjunctions and folding constants.
+    fn or(children: Vec<Nnf>) -> Nnf {
+        let mut flat = Vec::with_capacity(children.len());
+        for child in children {
+            match child {
+                Nnf::Constant(false) => {}
+                Nnf::Constant(true) => return Nnf::Constant(true),
+                Nnf::Or(grandchildren) => flat.extend(grandchildren),
+                child => flat.push(child),
+            }
+        }
+        match flat.len() {
+            0 => Nnf::Constant(false),
+            1 => flat.pop().unwrap_or(Nnf::Constant(false)),
+            _ => Nnf::Or(flat),
+        }
+    }
+}
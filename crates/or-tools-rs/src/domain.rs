@@ -0,0 +1,344 @@
+use smallvec::SmallVec;
+
+/// A set of integer values, stored as a sorted list of disjoint
+/// intervals.
+///
+/// This is what the builder uses for the domains of the variables and
+/// of the linear constraints. Any `IntoIterator<Item = (i64, i64)>` can
+/// be converted into a [`Domain`], where each item is an interval with
+/// both bounds included, so `[(0, 2), (4, 8)]` means [0, 2]∪[4, 8].
+/// The intervals are normalized: empty intervals are dropped, and
+/// overlapping or adjacent intervals are merged.
+///
+/// # Example
+///
+/// ```
+/// # use or_tools::Domain;
+/// let domain = Domain::from([(4, 8), (0, 2), (7, 10), (3, 1)]);
+/// assert_eq!(&[(0, 2), (4, 10)], domain.intervals());
+/// assert_eq!(10, domain.size());
+/// assert!(domain.contains(5));
+/// assert!(!domain.contains(3));
+/// assert_eq!("[0,2][4,10]", domain.to_string());
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct Domain {
+    intervals: SmallVec<[(i64, i64); 1]>,
+}
+
+impl Domain {
+    /// Returns the empty domain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::Domain;
+    /// assert!(Domain::empty().is_empty());
+    /// assert_eq!(Domain::empty(), Domain::new(1, 0));
+    /// ```
+    #[must_use]
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Returns the domain containing all the `i64` values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::Domain;
+    /// assert!(Domain::all().contains(i64::MIN));
+    /// assert!(Domain::all().contains(i64::MAX));
+    /// ```
+    #[must_use]
+    pub fn all() -> Self {
+        Self::new(i64::MIN, i64::MAX)
+    }
+
+    /// Returns the domain `[min, max]`, which is empty if `min > max`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::Domain;
+    /// let domain = Domain::new(-3, 5);
+    /// assert_eq!(Some(-3), domain.min());
+    /// assert_eq!(Some(5), domain.max());
+    /// assert_eq!(9, domain.size());
+    /// ```
+    #[must_use]
+    pub fn new(min: i64, max: i64) -> Self {
+        let mut intervals = SmallVec::new();
+        if min <= max {
+            intervals.push((min, max));
+        }
+        Self { intervals }
+    }
+
+    /// Returns the domain containing exactly the given values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::Domain;
+    /// let domain = Domain::from_values([5, 1, 2, 3, 7, 2]);
+    /// assert_eq!(&[(1, 3), (5, 5), (7, 7)], domain.intervals());
+    /// ```
+    #[must_use]
+    pub fn from_values(values: impl IntoIterator<Item = i64>) -> Self {
+        Self::from_intervals(values.into_iter().map(|v| (v, v)))
+    }
+
+    /// Returns the union of the given intervals, with both bounds
+    /// included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::Domain;
+    /// let domain = Domain::from_intervals([(5, 6), (0, 3), (2, 4)]);
+    /// assert_eq!(&[(0, 6)], domain.intervals());
+    /// ```
+    #[must_use]
+    pub fn from_intervals(intervals: impl IntoIterator<Item = (i64, i64)>) -> Self {
+        let mut intervals: SmallVec<[(i64, i64); 1]> = intervals
+            .into_iter()
+            .filter(|(min, max)| min <= max)
+            .collect();
+        intervals.sort_unstable();
+        let mut normalized: SmallVec<[(i64, i64); 1]> = SmallVec::new();
+        for (min, max) in intervals {
+            match normalized.last_mut() {
+                Some(last) if min <= last.1.saturating_add(1) => last.1 = last.1.max(max),
+                _ => normalized.push((min, max)),
+            }
+        }
+        Self {
+            intervals: normalized,
+        }
+    }
+
+    /// Returns the domain from its flattened representation
+    /// `[min_0, max_0, min_1, max_1, ...]`, as used in the protos.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::Domain;
+    /// let domain = Domain::from_flat_intervals(&[0, 2, 4, 8]);
+    /// assert_eq!(vec![0, 2, 4, 8], domain.to_flat_intervals());
+    /// ```
+    #[must_use]
+    pub fn from_flat_intervals(flat: &[i64]) -> Self {
+        Self::from_intervals(flat.chunks_exact(2).filter_map(|interval| match *interval {
+            [min, max] => Some((min, max)),
+            _ => None,
+        }))
+    }
+
+    /// Returns the flattened representation `[min_0, max_0, min_1,
+    /// max_1, ...]` of the domain, as used in the protos.
+    #[must_use]
+    pub fn to_flat_intervals(&self) -> Vec<i64> {
+        self.intervals
+            .iter()
+            .flat_map(|&(min, max)| [min, max])
+            .collect()
+    }
+
+    /// Returns the sorted, disjoint and non adjacent intervals of the
+    /// domain.
+    #[must_use]
+    pub fn intervals(&self) -> &[(i64, i64)] {
+        &self.intervals
+    }
+
+    /// Returns true if the domain contains no value.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns the number of values in the domain, saturated to
+    /// `u64::MAX`.
+    #[must_use]
+    pub fn size(&self) -> u64 {
+        self.intervals.iter().fold(0u64, |size, &(min, max)| {
+            size.saturating_add(max.abs_diff(min).saturating_add(1))
+        })
+    }
+
+    /// Returns the smallest value of the domain, `None` if it is empty.
+    #[must_use]
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|&(min, _)| min)
+    }
+
+    /// Returns the largest value of the domain, `None` if it is empty.
+    #[must_use]
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|&(_, max)| max)
+    }
+
+    /// Returns the value of the domain if it contains exactly one
+    /// value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::Domain;
+    /// assert_eq!(Some(3), Domain::new(3, 3).fixed_value());
+    /// assert_eq!(None, Domain::new(3, 4).fixed_value());
+    /// ```
+    #[must_use]
+    pub fn fixed_value(&self) -> Option<i64> {
+        match *self.intervals.as_slice() {
+            [(min, max)] if min == max => Some(min),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the domain contains the given value.
+    #[must_use]
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.intervals.partition_point(|&(_, max)| max < value);
+        self.intervals
+            .get(index)
+            .is_some_and(|&(min, _)| min <= value)
+    }
+
+    /// Returns the values of `i64` that are not in the domain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::Domain;
+    /// let domain = Domain::from([(0, 2), (4, 8)]).complement();
+    /// assert_eq!(
+    ///     &[(i64::MIN, -1), (3, 3), (9, i64::MAX)],
+    ///     domain.intervals()
+    /// );
+    /// assert_eq!(Domain::all(), Domain::empty().complement());
+    /// ```
+    #[must_use]
+    pub fn complement(&self) -> Self {
+        let mut intervals = SmallVec::new();
+        let mut next = Some(i64::MIN);
+        for &(min, max) in &self.intervals {
+            if let Some(next) = next
+                && next < min
+            {
+                intervals.push((next, min - 1));
+            }
+            next = max.checked_add(1);
+        }
+        if let Some(next) = next {
+            intervals.push((next, i64::MAX));
+        }
+        Self { intervals }
+    }
+
+    /// Returns the opposite of the values of the domain, i.e. `{-x | x
+    /// in self}`. As `-i64::MIN` does not fit in an `i64`, it is
+    /// mapped to `i64::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::Domain;
+    /// let domain = Domain::from([(0, 2), (4, 8)]).negation();
+    /// assert_eq!(&[(-8, -4), (-2, 0)], domain.intervals());
+    /// ```
+    #[must_use]
+    pub fn negation(&self) -> Self {
+        Self::from_intervals(
+            self.intervals
+                .iter()
+                .map(|&(min, max)| (max.saturating_neg(), min.saturating_neg())),
+        )
+    }
+
+    /// Returns the values that are in `self` or in `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::Domain;
+    /// let domain = Domain::new(0, 2).union(&Domain::from_values([3, 7]));
+    /// assert_eq!(&[(0, 3), (7, 7)], domain.intervals());
+    /// ```
+    #[must_use]
+    pub fn union(&self, other: &Domain) -> Self {
+        Self::from_intervals(self.intervals.iter().chain(&other.intervals).copied())
+    }
+
+    /// Returns the values that are both in `self` and in `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::Domain;
+    /// let domain = Domain::from([(0, 5), (8, 10)]).intersection(&Domain::new(4, 9));
+    /// assert_eq!(&[(4, 5), (8, 9)], domain.intervals());
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, other: &Domain) -> Self {
+        let mut intervals = SmallVec::new();
+        let (mut a, mut b) = (
+            self.intervals.iter().peekable(),
+            other.intervals.iter().peekable(),
+        );
+        while let (Some(&&(a_min, a_max)), Some(&&(b_min, b_max))) = (a.peek(), b.peek()) {
+            let (min, max) = (a_min.max(b_min), a_max.min(b_max));
+            if min <= max {
+                intervals.push((min, max));
+            }
+            if a_max < b_max {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { intervals }
+    }
+
+    /// Returns the sums `x + y` for `x` in `self` and `y` in `other`.
+    /// Sums that do not fit in an `i64` are saturated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::Domain;
+    /// let domain = Domain::from_values([0, 10]).addition(&Domain::new(1, 2));
+    /// assert_eq!(&[(1, 2), (11, 12)], domain.intervals());
+    /// ```
+    #[must_use]
+    pub fn addition(&self, other: &Domain) -> Self {
+        Self::from_intervals(self.intervals.iter().flat_map(|&(a_min, a_max)| {
+            other.intervals.iter().map(move |&(b_min, b_max)| {
+                (a_min.saturating_add(b_min), a_max.saturating_add(b_max))
+            })
+        }))
+    }
+}
+
+impl<I: IntoIterator<Item = (i64, i64)>> From<I> for Domain {
+    fn from(intervals: I) -> Self {
+        Self::from_intervals(intervals)
+    }
+}
+
+impl std::fmt::Display for Domain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (min, max) in &self.intervals {
+            write!(f, "[{min},{max}]")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for Domain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Domain({self})")
+    }
+}
@@ -53,6 +53,17 @@ const _LINK_OR_TOOLS_SYS: () = or_tools_sys::LINKED;
 /// Model builder for ergonomic and efficient model creation.
 pub mod builder;
 
+/// Automata over integer values, for the automaton constraint.
+pub mod automaton;
+
+mod domain;
+pub use domain::Domain;
+
+/// Statistics on the models, computed without calling the solver.
+pub mod stats;
+
+mod macros;
+
 /// Export of the CP SAT protobufs
 #[allow(
     warnings,
@@ -0,0 +1,134 @@
+/// Adds a linear constraint written as a comparison, and returns its
+/// [`Constraint`](crate::builder::Constraint).
+///
+/// The first argument is the [`CpModelBuilder`], then comes the
+/// comparison between two linear expressions, using one of `<=`, `<`,
+/// `>=`, `>`, `==` or `!=`. A bounded expression can be written with
+/// the chained forms `lo <= expr <= hi` and `hi >= expr >= lo`, where
+/// `lo` and `hi` are `i64`. The comparison can be followed by these
+/// optional arguments:
+///
+/// - `name = "..."` sets the name of the constraint with
+///   [`CpModelBuilder::set_constraint_name`],
+/// - `if b` enforces the constraint only if the literal `b` is true
+///   with [`CpModelBuilder::only_enforce_if`].
+///
+/// The comparison is split on the first comparison operator that is
+/// not inside parentheses, brackets or braces, so sub-expressions
+/// containing such operators or generic arguments must be
+/// parenthesized.
+///
+/// [`CpModelBuilder`]: crate::builder::CpModelBuilder
+/// [`CpModelBuilder::set_constraint_name`]: crate::builder::CpModelBuilder::set_constraint_name
+/// [`CpModelBuilder::only_enforce_if`]: crate::builder::CpModelBuilder::only_enforce_if
+///
+/// # Example
+///
+/// ```
+/// # use or_tools::builder::CpModelBuilder;
+/// # use or_tools::linear;
+/// # use or_tools::proto::CpSolverStatus;
+/// let mut model = CpModelBuilder::default();
+/// let x = model.new_int_var([(0, 10)]);
+/// let y = model.new_int_var([(0, 10)]);
+/// let z = model.new_int_var([(0, 10)]);
+/// let b = model.new_bool_var();
+/// linear!(model, 2 * x + 3 * y <= z + 10);
+/// let cst = linear!(model, 4 <= x - y <= 6, name = "gap");
+/// assert_eq!("gap", model.constraint_name(cst));
+/// linear!(model, z == 7, if b);
+/// linear!(model, z != 7, name = "not seven", if !b);
+/// model.maximize(x + y + 10 * b);
+///
+/// let response = model.solve();
+/// assert_eq!(response.status(), CpSolverStatus::Optimal);
+/// let (x, y, z) = (
+///     x.solution_value(&response),
+///     y.solution_value(&response),
+///     z.solution_value(&response),
+/// );
+/// assert!(2 * x + 3 * y <= z + 10);
+/// assert!((4..=6).contains(&(x - y)));
+/// assert_eq!(7, z);
+/// ```
+#[macro_export]
+macro_rules! linear {
+    // separates the comparison from the optional arguments
+    (@split $model:ident; [$($cmp:tt)+]; , $($opts:tt)*) => {{
+        let constraint = $crate::linear!(@cmp $model; []; $($cmp)+);
+        $crate::linear!(@opts $model, constraint; $($opts)*);
+        constraint
+    }};
+    (@split $model:ident; [$($cmp:tt)*]; $next:tt $($rest:tt)*) => {
+        $crate::linear!(@split $model; [$($cmp)* $next]; $($rest)*)
+    };
+    (@split $model:ident; [$($cmp:tt)+];) => {
+        $crate::linear!(@cmp $model; []; $($cmp)+)
+    };
+
+    // finds the first comparison operator
+    (@cmp $model:ident; [$($lhs:tt)+]; <= $($rest:tt)+) => {
+        $crate::linear!(@le $model; [$($lhs)+]; []; $($rest)+)
+    };
+    (@cmp $model:ident; [$($lhs:tt)+]; >= $($rest:tt)+) => {
+        $crate::linear!(@ge $model; [$($lhs)+]; []; $($rest)+)
+    };
+    (@cmp $model:ident; [$($lhs:tt)+]; < $($rhs:tt)+) => {
+        $model.add_lt($($lhs)+, $($rhs)+)
+    };
+    (@cmp $model:ident; [$($lhs:tt)+]; > $($rhs:tt)+) => {
+        $model.add_gt($($lhs)+, $($rhs)+)
+    };
+    (@cmp $model:ident; [$($lhs:tt)+]; == $($rhs:tt)+) => {
+        $model.add_eq($($lhs)+, $($rhs)+)
+    };
+    (@cmp $model:ident; [$($lhs:tt)+]; != $($rhs:tt)+) => {
+        $model.add_ne($($lhs)+, $($rhs)+)
+    };
+    (@cmp $model:ident; [$($lhs:tt)*]; $next:tt $($rest:tt)*) => {
+        $crate::linear!(@cmp $model; [$($lhs)* $next]; $($rest)*)
+    };
+
+    // `lhs <= rhs` or `lo <= expr <= hi`
+    (@le $model:ident; [$($lo:tt)+]; [$($expr:tt)+]; <= $($hi:tt)+) => {
+        $model.add_linear_constraint($($expr)+, $crate::Domain::new($($lo)+, $($hi)+))
+    };
+    (@le $model:ident; [$($lhs:tt)+]; [$($rhs:tt)*]; $next:tt $($rest:tt)*) => {
+        $crate::linear!(@le $model; [$($lhs)+]; [$($rhs)* $next]; $($rest)*)
+    };
+    (@le $model:ident; [$($lhs:tt)+]; [$($rhs:tt)+];) => {
+        $model.add_le($($lhs)+, $($rhs)+)
+    };
+
+    // `lhs >= rhs` or `hi >= expr >= lo`
+    (@ge $model:ident; [$($hi:tt)+]; [$($expr:tt)+]; >= $($lo:tt)+) => {
+        $model.add_linear_constraint($($expr)+, $crate::Domain::new($($lo)+, $($hi)+))
+    };
+    (@ge $model:ident; [$($lhs:tt)+]; [$($rhs:tt)*]; $next:tt $($rest:tt)*) => {
+        $crate::linear!(@ge $model; [$($lhs)+]; [$($rhs)* $next]; $($rest)*)
+    };
+    (@ge $model:ident; [$($lhs:tt)+]; [$($rhs:tt)+];) => {
+        $model.add_ge($($lhs)+, $($rhs)+)
+    };
+
+    // optional arguments
+    (@opts $model:ident, $constraint:ident;) => {};
+    (@opts $model:ident, $constraint:ident; name = $name:expr $(, $($rest:tt)*)?) => {
+        $model.set_constraint_name($constraint, ::core::convert::AsRef::<str>::as_ref(&$name));
+        $crate::linear!(@opts $model, $constraint; $($($rest)*)?);
+    };
+    (@opts $model:ident, $constraint:ident; if $literal:expr $(, $($rest:tt)*)?) => {
+        $model.only_enforce_if($constraint, [$literal]);
+        $crate::linear!(@opts $model, $constraint; $($($rest)*)?);
+    };
+
+    ($model:expr, $($args:tt)+) => {{
+        // evaluates the model expression once, whether it is a builder
+        // or a mutable reference to one
+        let model: &mut $crate::builder::CpModelBuilder = {
+            use ::core::borrow::BorrowMut as _;
+            $model.borrow_mut()
+        };
+        $crate::linear!(@split model; []; $($args)+)
+    }};
+}
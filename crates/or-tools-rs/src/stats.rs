@@ -0,0 +1,252 @@
+use crate::builder::ConstraintKind;
+use crate::{Domain, proto};
+use proto::constraint_proto::Constraint as CstEnum;
+use std::collections::BTreeMap;
+
+/// Maximal number of distinct domains listed one by one by the
+/// [`Display`](std::fmt::Display) of [`ModelStats`].
+const MAX_LISTED_DOMAINS: usize = 10;
+
+/// The shape of the domain of a variable.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DomainKind {
+    /// A domain without any value.
+    Empty,
+    /// A domain with a single value.
+    Fixed,
+    /// The domain `[0, 1]`.
+    Boolean,
+    /// A domain `[min, max]` without holes, other than the above.
+    Interval,
+    /// A domain made of several disjoint intervals.
+    Sparse,
+}
+
+impl DomainKind {
+    /// Returns the kind of the given domain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use or_tools::Domain;
+    /// # use or_tools::stats::DomainKind;
+    /// assert_eq!(DomainKind::Fixed, DomainKind::of(&Domain::new(3, 3)));
+    /// assert_eq!(DomainKind::Boolean, DomainKind::of(&Domain::new(0, 1)));
+    /// assert_eq!(DomainKind::Interval, DomainKind::of(&Domain::new(0, 2)));
+    /// assert_eq!(DomainKind::Sparse, DomainKind::of(&Domain::from_values([0, 2])));
+    /// ```
+    #[must_use]
+    pub fn of(domain: &Domain) -> Self {
+        match *domain.intervals() {
+            [] => Self::Empty,
+            [(min, max)] if min == max => Self::Fixed,
+            [(0, 1)] => Self::Boolean,
+            [_] => Self::Interval,
+            _ => Self::Sparse,
+        }
+    }
+}
+
+/// Statistics on a [`proto::CpModelProto`], computed without calling
+/// the solver.
+///
+/// Its [`Display`](std::fmt::Display) resembles the output of
+/// [`ffi::cp_model_stats`](crate::ffi::cp_model_stats).
+///
+/// # Example
+///
+/// ```
+/// # use or_tools::builder::{ConstraintKind, CpModelBuilder};
+/// # use or_tools::stats::{DomainKind, ModelStats};
+/// let mut model = CpModelBuilder::default();
+/// let x = model.new_int_var([(0, 10)]);
+/// let y = model.new_int_var([(0, 10)]);
+/// let b = model.new_bool_var();
+/// let cst = model.add_le(x + y, 12);
+/// model.only_enforce_if(cst, [b]);
+/// model.add_or([b]);
+/// model.maximize(x + 2 * y);
+///
+/// let stats = ModelStats::new(model.proto());
+/// assert_eq!((3, 1, 2), (stats.num_variables, stats.num_booleans, stats.num_integers));
+/// assert_eq!(Some(&2), stats.variables_by_domain_kind.get(&DomainKind::Interval));
+/// assert_eq!(Some(&1), stats.constraints.get(&ConstraintKind::Linear));
+/// assert_eq!(Some(&1), stats.enforced_constraints.get(&ConstraintKind::Linear));
+/// assert_eq!(2, stats.objective_size);
+/// assert_eq!(2, stats.num_linear_terms);
+/// assert_eq!(11, stats.largest_domain_size);
+/// assert_eq!(
+///     "Optimization model '':
+/// #Variables: 3 (#bools: 1) (#ints: 2) (#in objective: 2)
+///   - 1 Booleans in [0,1]
+///   - 2 in [0,10]
+/// #kBoolOr: 1 (#literals: 1)
+/// #kLinear: 1 (#enforced: 1) (#terms: 2)
+/// ",
+///     stats.to_string()
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModelStats {
+    /// The name of the model.
+    pub name: String,
+    /// Whether the model has an integer or floating point objective.
+    pub has_objective: bool,
+    /// The number of variables.
+    pub num_variables: usize,
+    /// The number of variables whose domain is `[0, 1]`.
+    pub num_booleans: usize,
+    /// The number of variables that are not Booleans.
+    pub num_integers: usize,
+    /// The number of variables for each kind of domain.
+    pub variables_by_domain_kind: BTreeMap<DomainKind, usize>,
+    /// The distinct domains of the variables, with their number of
+    /// variables, sorted by domain.
+    pub domains: Vec<(Domain, usize)>,
+    /// The number of values of the largest domain, saturated to
+    /// `u64::MAX`.
+    pub largest_domain_size: u64,
+    /// The number of constraints of each kind.
+    pub constraints: BTreeMap<ConstraintKind, usize>,
+    /// The number of constraints disabled with
+    /// [`CpModelBuilder::disable`](crate::builder::CpModelBuilder::disable),
+    /// which are not counted in the other fields. Only
+    /// [`CpModelBuilder::model_stats`](crate::builder::CpModelBuilder::model_stats)
+    /// knows about them: in a proto, they are empty constraints.
+    pub num_disabled: usize,
+    /// The number of constraints with enforcement literals, for each
+    /// kind.
+    pub enforced_constraints: BTreeMap<ConstraintKind, usize>,
+    /// The number of literals of the Boolean constraints
+    /// (`bool_or`, `bool_and`, `at_most_one`, `exactly_one` and
+    /// `bool_xor`), for each kind.
+    pub num_literals: BTreeMap<ConstraintKind, usize>,
+    /// The total number of terms of the linear constraints.
+    pub num_linear_terms: usize,
+    /// The number of terms of the objective.
+    pub objective_size: usize,
+}
+
+impl ModelStats {
+    /// Computes the statistics of the given model.
+    #[must_use]
+    pub fn new(model: &proto::CpModelProto) -> Self {
+        Self::with_constraints(model, &model.constraints)
+    }
+
+    /// Computes the statistics of the given model, counting only the
+    /// given constraints.
+    pub(crate) fn with_constraints<'a>(
+        model: &proto::CpModelProto,
+        constraints: impl IntoIterator<Item = &'a proto::ConstraintProto>,
+    ) -> Self {
+        let mut stats = ModelStats {
+            name: model.name.clone(),
+            has_objective: model.objective.is_some() || model.floating_point_objective.is_some(),
+            num_variables: model.variables.len(),
+            objective_size: model.objective.as_ref().map_or(0, |o| o.vars.len())
+                + model
+                    .floating_point_objective
+                    .as_ref()
+                    .map_or(0, |o| o.vars.len()),
+            ..Default::default()
+        };
+
+        let mut domains = BTreeMap::<Vec<(i64, i64)>, usize>::new();
+        for var in &model.variables {
+            let domain = Domain::from_flat_intervals(&var.domain);
+            let kind = DomainKind::of(&domain);
+            if kind == DomainKind::Boolean {
+                stats.num_booleans += 1;
+            } else {
+                stats.num_integers += 1;
+            }
+            *stats.variables_by_domain_kind.entry(kind).or_default() += 1;
+            stats.largest_domain_size = stats.largest_domain_size.max(domain.size());
+            *domains.entry(domain.intervals().to_vec()).or_default() += 1;
+        }
+        stats.domains = domains
+            .into_iter()
+            .map(|(intervals, count)| (Domain::from_intervals(intervals), count))
+            .collect();
+
+        for cst in constraints {
+            let kind = ConstraintKind::of(cst);
+            *stats.constraints.entry(kind).or_default() += 1;
+            if !cst.enforcement_literal.is_empty() {
+                *stats.enforced_constraints.entry(kind).or_default() += 1;
+            }
+            match &cst.constraint {
+                Some(
+                    CstEnum::BoolOr(cst)
+                    | CstEnum::BoolAnd(cst)
+                    | CstEnum::AtMostOne(cst)
+                    | CstEnum::ExactlyOne(cst)
+                    | CstEnum::BoolXor(cst),
+                ) => *stats.num_literals.entry(kind).or_default() += cst.literals.len(),
+                Some(CstEnum::Linear(cst)) => stats.num_linear_terms += cst.vars.len(),
+                _ => (),
+            }
+        }
+        stats
+    }
+}
+
+impl std::fmt::Display for ModelStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let model_kind = if self.has_objective {
+            "Optimization"
+        } else {
+            "Satisfaction"
+        };
+        writeln!(f, "{model_kind} model '{}':", self.name)?;
+        write!(
+            f,
+            "#Variables: {} (#bools: {}) (#ints: {})",
+            self.num_variables, self.num_booleans, self.num_integers
+        )?;
+        if self.has_objective {
+            write!(f, " (#in objective: {})", self.objective_size)?;
+        }
+        writeln!(f)?;
+        if self.domains.len() <= MAX_LISTED_DOMAINS {
+            for (domain, count) in &self.domains {
+                if DomainKind::of(domain) == DomainKind::Boolean {
+                    writeln!(f, "  - {count} Booleans in {domain}")?;
+                } else {
+                    writeln!(f, "  - {count} in {domain}")?;
+                }
+            }
+        } else {
+            let all = self
+                .domains
+                .iter()
+                .fold(Domain::empty(), |all, (domain, _)| all.union(domain));
+            writeln!(
+                f,
+                "  - {} different domains in [{},{}] with a largest size of {}",
+                self.domains.len(),
+                all.min().unwrap_or_default(),
+                all.max().unwrap_or_default(),
+                self.largest_domain_size
+            )?;
+        }
+        for (&kind, count) in &self.constraints {
+            write!(f, "#{}: {count}", kind.case_name())?;
+            if let Some(enforced) = self.enforced_constraints.get(&kind) {
+                write!(f, " (#enforced: {enforced})")?;
+            }
+            if let Some(literals) = self.num_literals.get(&kind) {
+                write!(f, " (#literals: {literals})")?;
+            }
+            if kind == ConstraintKind::Linear {
+                write!(f, " (#terms: {})", self.num_linear_terms)?;
+            }
+            writeln!(f)?;
+        }
+        if self.num_disabled > 0 {
+            writeln!(f, "#disabled: {}", self.num_disabled)?;
+        }
+        Ok(())
+    }
+}
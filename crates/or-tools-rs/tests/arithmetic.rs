@@ -0,0 +1,63 @@
+use or_tools::builder::{CpModelBuilder, Error, LinearExpr};
+use or_tools::proto::CpSolverStatus;
+
+#[test]
+fn divisors_containing_zero() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 10)]);
+    let q = model.new_int_var([(0, 10)]);
+    let holes = model.new_int_var([(-5, -1), (1, 5)]);
+    let sum = model.new_int_var([(1, 5)]);
+
+    assert_eq!(Err(Error::DivisionByZero), model.add_division_eq(q, x, 0));
+    assert_eq!(Err(Error::DivisionByZero), model.add_division_eq(q, x, x));
+    // 2 * holes - 6 is zero for holes == 3
+    assert_eq!(
+        Err(Error::DivisionByZero),
+        model.add_modulo_eq(q, x, LinearExpr::from((2, holes)) - 6)
+    );
+    // x + sum - 1 is zero for x == 0 and sum == 1
+    assert_eq!(
+        Err(Error::DivisionByZero),
+        model.add_division_eq(q, x, LinearExpr::from(x) + sum - 1)
+    );
+    assert!(model.proto().constraints.is_empty());
+
+    assert!(model.add_division_eq(q, x, holes).is_ok());
+    // 2 * holes + 1 is odd
+    assert!(
+        model
+            .add_division_eq(q, x, LinearExpr::from((2, holes)) + 1)
+            .is_ok()
+    );
+    assert!(model.add_modulo_eq(q, x, LinearExpr::from(x) + sum).is_ok());
+    assert!(model.add_modulo_eq(q, x, 3).is_ok());
+    assert_eq!(4, model.proto().constraints.len());
+}
+
+#[test]
+fn non_linear_equations() -> Result<(), Error> {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(-10, 10)]);
+    let y = model.new_int_var([(-10, 10)]);
+    let square = model.new_int_var([(0, 100)]);
+    let abs = model.new_int_var([(0, 10)]);
+    let half = model.new_int_var([(-5, 5)]);
+    let parity = model.new_int_var([(-1, 1)]);
+
+    model.add_multiplication_eq(square, [x, x]);
+    model.add_abs_eq(abs, y);
+    model.add_division_eq(half, x, 2)?;
+    model.add_modulo_eq(parity, x, 2)?;
+    model.add_eq(square, 49);
+    model.add_eq(abs, 4);
+    model.maximize(LinearExpr::from(y) - x);
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(-7, x.solution_value(&response));
+    assert_eq!(4, y.solution_value(&response));
+    assert_eq!(-3, half.solution_value(&response));
+    assert_eq!(-1, parity.solution_value(&response));
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use or_tools::automaton::Automaton;
+use or_tools::builder::{CpModelBuilder, LinearExpr};
+use or_tools::proto::CpSolverStatus;
+
+#[test]
+fn pattern_repetitions() -> Result<(), Box<dyn std::error::Error>> {
+    let automaton = Automaton::from_pattern("0{2} 1{1,} 2{0,2} 3?")?;
+    assert!(automaton.accepts([0, 0, 1]));
+    assert!(automaton.accepts([0, 0, 1, 1, 1, 2, 2, 3]));
+    assert!(!automaton.accepts([0, 1]));
+    assert!(!automaton.accepts([0, 0, 2]));
+    assert!(!automaton.accepts([0, 0, 1, 2, 2, 2]));
+    assert!(!automaton.accepts([0, 0, 1, 3, 3]));
+    Ok(())
+}
+
+#[test]
+fn pattern_alternations_and_sets() -> Result<(), Box<dyn std::error::Error>> {
+    let automaton: Automaton = "(-1 | [2 4..=6])+ | 0".parse()?;
+    assert!(automaton.accepts([0]));
+    assert!(automaton.accepts([-1, 5, 2, 6]));
+    assert!(!automaton.accepts([0, 0]));
+    assert!(!automaton.accepts([3]));
+    assert!(!automaton.accepts([]));
+    Ok(())
+}
+
+#[test]
+fn empty_pattern() -> Result<(), Box<dyn std::error::Error>> {
+    let automaton = Automaton::from_pattern("")?;
+    assert!(automaton.accepts([]));
+    assert!(!automaton.accepts([0]));
+    Ok(())
+}
+
+#[test]
+fn invalid_patterns() {
+    for (pattern, position) in [
+        ("1 (0", 4),
+        ("1 0)", 3),
+        ("[1..=x]", 5),
+        ("1{3,2}", 2),
+        ("[3..=1]", 1),
+        ("*", 0),
+    ] {
+        let err = Automaton::from_pattern(pattern).err();
+        assert_eq!(Some(position), err.map(|e| e.position()), "{pattern}");
+    }
+}
+
+#[test]
+fn too_large_patterns() {
+    for (pattern, position) in [
+        ("[0..=1000000000]", 1),
+        ("[0..=40000 50000..=90000]", 11),
+        ("[-9223372036854775808..=9223372036854775807]", 1),
+        ("1 2{1000000000}", 3),
+        ("(1{1000}){1000}", 9),
+        ("(){4294967295}", 2),
+    ] {
+        let err = Automaton::from_pattern(pattern).err();
+        assert_eq!(Some(position), err.map(|e| e.position()), "{pattern}");
+    }
+    assert!(Automaton::from_pattern("[0..=65535]").is_ok());
+    assert!(Automaton::from_pattern("1{1000}").is_ok());
+}
+
+#[test]
+fn too_many_states() {
+    // the automaton must remember the last 26 values
+    let err = Automaton::from_pattern("[0 1]* 0 [0 1]{25}").err();
+    assert_eq!(
+        Some("invalid automaton pattern at position 0: pattern too large".to_string()),
+        err.map(|e| e.to_string())
+    );
+
+    let automaton = Automaton::from_pattern("[0 1]* 0 [0 1]{9}");
+    assert_eq!(
+        Some((true, false)),
+        automaton
+            .map(|a| (
+                a.accepts([1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1]),
+                a.accepts([0; 9])
+            ))
+            .ok()
+    );
+}
+
+#[test]
+fn night_shift_rostering() -> Result<(), Box<dyn std::error::Error>> {
+    const NIGHT: i64 = 1;
+    const OFF: i64 = 2;
+
+    let mut model = CpModelBuilder::default();
+    let shifts: Vec<_> = (0..10).map(|_| model.new_int_var([(0, 2)])).collect();
+    let rule: Automaton = "([0 2] | 1{1,3} 2 2)*".parse()?;
+    model.add_automaton(
+        shifts.iter().copied(),
+        rule.starting_state,
+        rule.final_states.clone(),
+        rule.transitions.clone(),
+    );
+
+    let mut nights = LinearExpr::default();
+    for &shift in &shifts {
+        let is_night = model.new_bool_var();
+        let cst = model.add_eq(shift, NIGHT);
+        model.only_enforce_if(cst, [is_night]);
+        let cst = model.add_ne(shift, NIGHT);
+        model.only_enforce_if(cst, [!is_night]);
+        nights += is_night;
+    }
+    model.maximize(nights);
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    let roster: Vec<_> = shifts.iter().map(|s| s.solution_value(&response)).collect();
+    assert!(rule.accepts(roster.iter().copied()));
+    // 3 nights and 2 days off, twice
+    assert!((response.objective_value - 6.0).abs() < 1e-6);
+    assert_eq!(4, roster.iter().filter(|&&s| s == OFF).count());
+    Ok(())
+}
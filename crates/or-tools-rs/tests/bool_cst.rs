@@ -23,3 +23,25 @@ fn not_feasible() {
     assert!(!y.solution_value(&response));
     assert!((!y).solution_value(&response));
 }
+
+#[test]
+fn cached_constants() {
+    let mut model = CpModelBuilder::default();
+    let t = model.true_literal();
+    let f = model.false_literal();
+    let two = model.new_constant(2);
+    assert_eq!(t, model.true_literal());
+    assert_eq!(!t, f);
+    assert_eq!(two, model.new_constant(2));
+    assert_ne!(two, model.new_constant(-2));
+    // 1 (shared by both literals), 2 and -2
+    assert_eq!(3, model.proto().variables.len());
+
+    let x = model.new_bool_var();
+    model.add_or([x, f]);
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert!(x.solution_value(&response));
+    assert!(t.solution_value(&response));
+    assert_eq!(2, two.solution_value(&response));
+}
@@ -0,0 +1,111 @@
+use or_tools::builder::{BoolExpr, BoolVar, CpModelBuilder};
+use or_tools::proto::{CpSolverResponse, CpSolverStatus};
+
+/// Evaluates the formula on the given values of the first variables
+/// of the model.
+fn evaluate(formula: &BoolExpr, values: &[bool]) -> bool {
+    let response = CpSolverResponse {
+        solution: values.iter().map(|&v| i64::from(v)).collect(),
+        ..Default::default()
+    };
+    formula.solution_value(&response)
+}
+
+/// Returns the model with the literals fixed to the given assignment.
+fn fixed(model: &CpModelBuilder, literals: &[BoolVar], assignment: u32) -> CpModelBuilder {
+    let mut fixed = model.clone();
+    fixed.add_and(literals.iter().enumerate().map(|(i, &literal)| {
+        if assignment & (1 << i) == 0 {
+            !literal
+        } else {
+            literal
+        }
+    }));
+    fixed
+}
+
+fn values(literals: &[BoolVar], assignment: u32) -> Vec<bool> {
+    (0..literals.len())
+        .map(|i| assignment & (1 << i) != 0)
+        .collect()
+}
+
+#[test]
+fn operators() {
+    let mut model = CpModelBuilder::default();
+    let [a, b, c] = [(); 3].map(|()| model.new_bool_var());
+    assert_eq!(
+        BoolExpr::And(vec![a.into(), b.into(), (!c).into()]),
+        a & b & !c
+    );
+    assert_eq!(BoolExpr::Or(vec![a.into(), b.into(), c.into()]), a | b | c);
+    assert_eq!(BoolExpr::from(a), !!BoolExpr::from(a));
+    assert_eq!(BoolExpr::from(!a), !BoolExpr::from(a));
+    assert_eq!(BoolExpr::Not(Box::new(a & b)), !(a & b));
+    assert_eq!(a & b, !!(a & b));
+    assert_eq!(
+        BoolExpr::Iff(Box::new(a.into()), Box::new(b | c)),
+        a.iff(b | c)
+    );
+    assert!(evaluate(&(a & !b).implies(c), &[true, false, true]));
+    assert!(!evaluate(&(a & !b).implies(c), &[true, false, false]));
+}
+
+#[test]
+fn compliance_rules() {
+    let mut model = CpModelBuilder::default();
+    let literals = [(); 4].map(|()| model.new_bool_var());
+    let [a, b, c, d] = literals;
+    let rule = (a & b).implies(c | !d) & c.iff(a | d) & !(b & d & !a);
+    model.add_bool_expr(rule.clone());
+
+    for assignment in 0..1 << literals.len() {
+        let response = fixed(&model, &literals, assignment).solve();
+        let expected = if evaluate(&rule, &values(&literals, assignment)) {
+            CpSolverStatus::Optimal
+        } else {
+            CpSolverStatus::Infeasible
+        };
+        assert_eq!(expected, response.status(), "{assignment:b}");
+    }
+}
+
+#[test]
+fn equivalent_literals() {
+    let mut model = CpModelBuilder::default();
+    let literals = [(); 3].map(|()| model.new_bool_var());
+    let [a, b, c] = literals;
+    let formulas = [
+        a & (b | !c),
+        (a | b).iff(!c),
+        !a.implies(b & c),
+        BoolExpr::any([a, b]) | false,
+    ];
+    let indicators: Vec<_> = formulas
+        .iter()
+        .map(|formula| model.new_bool_expr_var(formula.clone()))
+        .collect();
+
+    for assignment in 0..1 << literals.len() {
+        let response = fixed(&model, &literals, assignment).solve();
+        assert_eq!(response.status(), CpSolverStatus::Optimal);
+        for (formula, indicator) in formulas.iter().zip(&indicators) {
+            assert_eq!(
+                evaluate(formula, &values(&literals, assignment)),
+                indicator.solution_value(&response),
+                "{formula:?} {assignment:b}"
+            );
+        }
+    }
+}
+
+#[test]
+fn literals_need_no_auxiliary_variable() {
+    let mut model = CpModelBuilder::default();
+    let [a, b] = [(); 2].map(|()| model.new_bool_var());
+    model.add_bool_expr(a & !b & true);
+    model.add_bool_expr(a | b | false);
+    assert_eq!(2, model.proto().variables.len());
+    assert_eq!(b, model.new_bool_expr_var(BoolExpr::all([b]) & true));
+    assert_eq!(2, model.proto().variables.len());
+}
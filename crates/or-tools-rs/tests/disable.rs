@@ -0,0 +1,184 @@
+use or_tools::builder::{ConstraintKind, CpModelBuilder, Error, LinearExpr};
+use or_tools::proto::constraint_proto::Constraint;
+use or_tools::proto::{CpSolverResponse, CpSolverStatus};
+
+#[test]
+fn toggle_conflicting_constraints() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 10)]);
+    let y = model.new_int_var([(0, 10)]);
+    let low = model.add_le(x + y, 4);
+    let high = model.add_ge(x, 6);
+    model.maximize(x + y);
+    assert_eq!(model.solve().status(), CpSolverStatus::Infeasible);
+
+    model.disable(high);
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(4, x.solution_value(&response) + y.solution_value(&response));
+
+    model.disable(low);
+    model.enable(high);
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(
+        20,
+        x.solution_value(&response) + y.solution_value(&response)
+    );
+
+    model.enable(low);
+    assert_eq!(model.solve().status(), CpSolverStatus::Infeasible);
+}
+
+#[test]
+fn handles_stay_valid() {
+    let mut model = CpModelBuilder::default();
+    let [a, b, c] = [(); 3].map(|()| model.new_bool_var());
+    let first = model.add_or([a, b]);
+    model.set_constraint_name(first, "first");
+    let second = model.add_and([!a, !b]);
+    model.set_constraint_name(second, "second");
+
+    model.disable(first);
+    model.disable(first);
+    assert!(!model.is_enabled(first));
+    assert!(model.is_enabled(second));
+    assert_eq!(2, model.proto().constraints.len());
+    assert_eq!(Some(first), model.constraint_by_name("first"));
+    assert_eq!(Some(second), model.constraint_by_name("second"));
+    let kinds: Vec<_> = model
+        .constraints()
+        .map(|view| (view.kind, view.enabled))
+        .collect();
+    assert_eq!(
+        vec![
+            (ConstraintKind::BoolOr, false),
+            (ConstraintKind::BoolAnd, true)
+        ],
+        kinds
+    );
+    assert_eq!(
+        vec![(ConstraintKind::BoolAnd, 1)],
+        model.constraint_counts().into_iter().collect::<Vec<_>>()
+    );
+
+    // changes made while disabled are kept
+    model.set_constraint_name(first, "renamed");
+    model.only_enforce_if(first, [c]);
+    let literals: Vec<_> = model
+        .constraints()
+        .map(|view| view.enforcement_literals)
+        .collect();
+    assert_eq!(vec![vec![c], vec![]], literals);
+    model.enable(first);
+    model.enable(first);
+    assert!(model.is_enabled(first));
+    assert_eq!("renamed", model.constraint_name(first));
+    let views: Vec<_> = model.constraints().collect();
+    let [view, _] = views.as_slice() else {
+        return assert_eq!(2, views.len());
+    };
+    assert_eq!(ConstraintKind::BoolOr, view.kind);
+    assert_eq!(vec![c], view.enforcement_literals);
+}
+
+#[test]
+fn re_solve_after_each_toggle() {
+    let mut model = CpModelBuilder::default();
+    let jobs: Vec<_> = (0..4).map(|_| model.new_bool_var()).collect();
+    let caps: Vec<_> = jobs.iter().map(|&job| model.add_and([!job])).collect();
+    model.maximize(LinearExpr::sum(jobs.iter().copied()));
+
+    for (disabled, &cap) in caps.iter().enumerate() {
+        let response = model.solve();
+        assert_eq!(response.status(), CpSolverStatus::Optimal);
+        let count = jobs
+            .iter()
+            .filter(|job| job.solution_value(&response))
+            .count();
+        assert_eq!(disabled, count);
+        model.disable(cap);
+    }
+    let response = model.solve();
+    assert!(jobs.iter().all(|job| job.solution_value(&response)));
+}
+
+#[test]
+fn additions_to_disabled_constraints_are_kept() -> Result<(), Error> {
+    let mut model = CpModelBuilder::default();
+    let start = model.new_int_var([(0, 10)]);
+    let task = model.new_fixed_size_interval_var(start, 3);
+    let cumulative = model.add_cumulative(2, [(task, 1)]);
+    let [a, b, c] = [(); 3].map(|()| model.new_bool_var());
+    let circuit = model.add_circuit([(0, 1, a)]);
+    let reservoir = model.add_reservoir(0, 5)?;
+    model.add_reservoir_event(reservoir, 1, 2, None);
+
+    model.disable(cumulative);
+    model.disable(circuit);
+    model.disable(reservoir);
+    model.add_cumulative_demand(cumulative, task, 2);
+    model.add_circuit_arc(circuit, 1, 0, b);
+    model.add_reservoir_event(reservoir, 3, -1, Some(c));
+    assert_eq!(
+        vec![(ConstraintKind::Interval, 1)],
+        model.constraint_counts().into_iter().collect::<Vec<_>>()
+    );
+
+    model.enable(cumulative);
+    model.enable(circuit);
+    model.enable(reservoir);
+    let sizes: Vec<_> = model
+        .proto()
+        .constraints
+        .iter()
+        .map(|cst| match &cst.constraint {
+            Some(Constraint::Interval(_)) => ("interval", vec![]),
+            Some(Constraint::Cumulative(cst)) => ("cumulative", vec![cst.demands.len()]),
+            Some(Constraint::Circuit(cst)) => ("circuit", vec![cst.tails.len(), cst.heads.len()]),
+            Some(Constraint::Reservoir(cst)) => (
+                "reservoir",
+                vec![cst.time_exprs.len(), cst.active_literals.len()],
+            ),
+            _ => ("other", vec![]),
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            ("interval", vec![]),
+            ("cumulative", vec![2]),
+            ("circuit", vec![2, 2]),
+            ("reservoir", vec![2, 2]),
+        ],
+        sizes
+    );
+    let response = CpSolverResponse {
+        solution: vec![0, 1, 1, 1],
+        ..Default::default()
+    };
+    assert_eq!(vec![0, 1], model.circuit_tour(circuit, &response));
+    Ok(())
+}
+
+#[test]
+fn disabled_intervals_keep_their_accessors() {
+    let mut model = CpModelBuilder::default();
+    let start = model.new_int_var([(0, 10)]);
+    let present = model.new_bool_var();
+    let end = model.new_int_var([(0, 10)]);
+    let task = model.new_optional_interval_var(start, 3, end, present);
+    model.disable(task.constraint());
+    assert_eq!(Some(task), model.interval_var(0));
+    assert_eq!(Some(present), model.interval_presence(task));
+    let response = CpSolverResponse {
+        solution: vec![2, 1, 5],
+        ..Default::default()
+    };
+    assert_eq!(5, model.interval_end(task).solution_value(&response));
+    assert_eq!(3, model.interval_size(task).solution_value(&response));
+
+    // handles of other models are ignored
+    let mut other = CpModelBuilder::default();
+    other.disable(task.constraint());
+    assert_eq!(0, other.proto().constraints.len());
+}
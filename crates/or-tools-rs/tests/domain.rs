@@ -0,0 +1,74 @@
+use or_tools::Domain;
+use or_tools::builder::CpModelBuilder;
+use or_tools::proto::CpSolverStatus;
+
+#[test]
+fn normalization() {
+    let domain = Domain::from([(10, 12), (0, 3), (4, 5), (2, 2), (8, 7), (14, 20)]);
+    assert_eq!(&[(0, 5), (10, 12), (14, 20)], domain.intervals());
+    assert_eq!(vec![0, 5, 10, 12, 14, 20], domain.to_flat_intervals());
+    assert_eq!(
+        domain,
+        Domain::from_flat_intervals(&domain.to_flat_intervals())
+    );
+    assert_eq!(Some(0), domain.min());
+    assert_eq!(Some(20), domain.max());
+    assert_eq!(16, domain.size());
+    assert!(Domain::from([(i64::MAX, i64::MAX), (i64::MIN, i64::MAX - 1)]) == Domain::all());
+    assert_eq!(u64::MAX, Domain::all().size());
+}
+
+#[test]
+fn membership() {
+    let domain = Domain::from_values([-4, 0, 1, 2, 9]);
+    for value in -6..12 {
+        assert_eq!(
+            [-4, 0, 1, 2, 9].contains(&value),
+            domain.contains(value),
+            "{value}"
+        );
+    }
+    assert!(!Domain::empty().contains(0));
+    assert_eq!(None, Domain::empty().min());
+}
+
+#[test]
+fn set_operations() {
+    let a = Domain::from([(0, 4), (8, 10)]);
+    let b = Domain::from([(3, 8), (12, 12)]);
+    assert_eq!(&[(0, 10), (12, 12)], a.union(&b).intervals());
+    assert_eq!(&[(3, 4), (8, 8)], a.intersection(&b).intervals());
+    assert_eq!(&[(-10, -8), (-4, 0)], a.negation().intervals());
+    assert_eq!(&[(3, 18), (20, 22)], a.addition(&b).intervals());
+    assert_eq!(a, a.complement().complement());
+    assert!(a.intersection(&a.complement()).is_empty());
+    assert_eq!(Domain::all(), a.union(&a.complement()));
+    assert_eq!(
+        &[(i64::MAX, i64::MAX)],
+        Domain::new(i64::MIN, i64::MIN).negation().intervals()
+    );
+    assert_eq!(
+        &[(i64::MAX - 1, i64::MAX)],
+        Domain::new(i64::MAX - 1, i64::MAX)
+            .addition(&Domain::new(0, 5))
+            .intervals()
+    );
+}
+
+#[test]
+fn variables_and_constraints() {
+    let mut model = CpModelBuilder::default();
+    let even = Domain::from_values((0..=10).map(|v| 2 * v));
+    let x = model.new_int_var(even.clone());
+    let y = model.new_int_var([(0, 20)]);
+    model.add_linear_constraint([(1, x), (1, y)], even.complement());
+    model.add_linear_constraint(y, Domain::new(5, 8).union(&Domain::new(12, 15)));
+    model.maximize([(1, x), (1, y)]);
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    let (x, y) = (x.solution_value(&response), y.solution_value(&response));
+    assert!(even.contains(x));
+    assert_eq!(20, x);
+    assert_eq!(15, y);
+}
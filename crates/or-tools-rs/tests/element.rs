@@ -0,0 +1,69 @@
+use or_tools::builder::{CpModelBuilder, LinearExpr};
+use or_tools::proto::CpSolverStatus;
+
+#[test]
+fn element_of_constants() {
+    let mut model = CpModelBuilder::default();
+    let index = model.new_int_var([(0, 3)]);
+    let target = model.new_int_var([(-10, 50)]);
+    model.add_element(index, [7, 42, -3, 12], target);
+    model.add_ne(index, 1);
+    model.maximize(target);
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(3, index.solution_value(&response));
+    assert_eq!(12, target.solution_value(&response));
+}
+
+#[test]
+fn element_of_variables() {
+    let mut model = CpModelBuilder::default();
+    let index = model.new_int_var([(0, 2)]);
+    let x = model.new_int_var([(0, 5)]);
+    let y = model.new_int_var([(0, 5)]);
+    let z = model.new_int_var([(0, 5)]);
+    // only `2 * z` can reach 10
+    model.add_element(
+        index,
+        [LinearExpr::from(x), LinearExpr::from(y) + 1, 2 * z],
+        10,
+    );
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(2, index.solution_value(&response));
+    assert_eq!(5, z.solution_value(&response));
+}
+
+#[test]
+fn inverse_assignment() {
+    let costs = [[9, 2, 7, 8], [6, 4, 3, 7], [5, 8, 1, 8], [7, 6, 9, 4]];
+
+    let mut model = CpModelBuilder::default();
+    // the task of each worker, and the worker of each task
+    let task: Vec<_> = (0..4).map(|_| model.new_int_var([(0, 3)])).collect();
+    let worker: Vec<_> = (0..4).map(|_| model.new_int_var([(0, 3)])).collect();
+    model.add_inverse(&task, &worker);
+
+    let mut total = LinearExpr::default();
+    for (&t, row) in task.iter().zip(costs) {
+        let cost = model.new_int_var([(0, 9)]);
+        model.add_element(t, row, cost);
+        total += cost;
+    }
+    model.minimize(total);
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert!((response.objective_value - 13.0).abs() < 1e-6);
+    let tasks: Vec<_> = task.iter().map(|t| t.solution_value(&response)).collect();
+    assert_eq!(vec![1, 0, 2, 3], tasks);
+    for (w, &t) in (0..).zip(&tasks) {
+        let assigned = usize::try_from(t)
+            .ok()
+            .and_then(|t| worker.get(t))
+            .map(|v| v.solution_value(&response));
+        assert_eq!(Some(w), assigned);
+    }
+}
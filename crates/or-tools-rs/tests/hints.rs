@@ -0,0 +1,111 @@
+use or_tools::builder::{CpModelBuilder, IntVar, LinearExpr};
+use or_tools::proto::{CpSolverResponse, CpSolverStatus, SatParameters};
+
+fn hints(model: &CpModelBuilder) -> Vec<(i32, i64)> {
+    let hint = model.proto().solution_hint.clone().unwrap_or_default();
+    let mut hints: Vec<_> = hint.vars.into_iter().zip(hint.values).collect();
+    hints.sort_unstable();
+    hints
+}
+
+#[test]
+fn hints_replace_previous_ones() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 10)]);
+    let b = model.new_bool_var();
+    let y = model.new_int_var([(0, 10)]);
+    model.add_hint(x, 3);
+    model.add_hint(b, 1);
+    let mut response = CpSolverResponse {
+        solution: vec![7, 0],
+        ..Default::default()
+    };
+    model.add_hints_from_response(&response);
+    assert_eq!(vec![(0, 7), (1, 0)], hints(&model));
+    assert!(!model.hint_is_complete());
+
+    response.solution = vec![1, 1, 5];
+    model.add_hints_from_response_for([IntVar::from(!b), y], &response);
+    assert_eq!(vec![(0, 7), (1, 1), (2, 5)], hints(&model));
+    assert!(model.hint_is_complete());
+
+    model.add_hint(x, 2);
+    model.add_hint(!b, 1);
+    assert_eq!(vec![(0, 2), (1, 0), (2, 5)], hints(&model));
+}
+
+#[test]
+fn loaded_hints_are_replaced() -> Result<(), Box<dyn std::error::Error>> {
+    let mut model = CpModelBuilder::default();
+    let [x, y] = [(); 2].map(|()| model.new_int_var([(0, 10)]));
+    model.add_hint(y, 4);
+    model.add_hint(x, 2);
+
+    let mut model = CpModelBuilder::from_proto(model.into_proto())?;
+    model.add_hint(x, 3);
+    assert_eq!(vec![(0, 3), (1, 4)], hints(&model));
+    model.del_hints();
+    model.add_hint(y, 5);
+    model.add_hint(y, 6);
+    assert_eq!(vec![(1, 6)], hints(&model));
+    Ok(())
+}
+
+#[test]
+fn hints_follow_names() {
+    let mut previous = CpModelBuilder::default();
+    for name in ["a", "", "b", "c"] {
+        previous.new_int_var_with_name([(0, 10)], name);
+    }
+    let response = CpSolverResponse {
+        solution: vec![1, 2, 3, 4],
+        ..Default::default()
+    };
+
+    let mut model = CpModelBuilder::default();
+    for name in ["c", "", "d", "a"] {
+        model.new_int_var_with_name([(0, 10)], name);
+    }
+    model.add_hints_from_named_response(previous.proto(), &response);
+    assert_eq!(vec![(0, 4), (3, 1)], hints(&model));
+}
+
+#[test]
+fn warm_start_modified_model() {
+    let mut model = CpModelBuilder::default();
+    let items: Vec<_> = (0..6).map(|_| model.new_bool_var()).collect();
+    let weights = [3, 4, 5, 6, 7, 8];
+    let values = [4, 5, 7, 8, 9, 11];
+    model.add_le(LinearExpr::weighted_sum(items.iter().copied(), weights), 15);
+    model.maximize(LinearExpr::weighted_sum(items.iter().copied(), values));
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+
+    model.add_hints_from_response(&response);
+    assert!(model.hint_is_complete());
+    assert!(model.hint_is_feasible());
+    let params = SatParameters {
+        fix_variables_to_their_hinted_value: Some(true),
+        ..Default::default()
+    };
+    let replay = model.solve_with_parameters(&params);
+    assert_eq!(replay.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.solution, replay.solution);
+
+    // a new item leaves the old hint incomplete, hence not feasible
+    // until the item is hinted
+    let extra = model.new_bool_var();
+    assert!(!model.hint_is_complete());
+    assert!(!model.hint_is_feasible());
+    model.add_hint(extra, 0);
+    assert!(model.hint_is_feasible());
+
+    // a tighter capacity makes it stale
+    model.add_le(LinearExpr::weighted_sum(items.iter().copied(), weights), 10);
+    assert!(model.hint_is_complete());
+    assert!(!model.hint_is_feasible());
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    model.add_hints_from_response(&response);
+    assert!(model.hint_is_feasible());
+}
@@ -0,0 +1,121 @@
+use or_tools::Domain;
+use or_tools::builder::{Constraint, ConstraintKind, CpModelBuilder, IntVar};
+
+#[test]
+fn scheduling_model_views() {
+    let mut model = CpModelBuilder::default();
+    let [start_a, start_b] =
+        ["start a", "start b"].map(|name| model.new_int_var_with_name([(0, 10)], name));
+    let present = model.new_bool_var_with_name("present");
+    let capacity = model.new_int_var_with_name([(1, 2), (4, 4)], "capacity");
+    let a = model.new_fixed_size_interval_var(start_a, 3);
+    let b = model.new_optional_interval_var(start_b, 2, start_b + 2, present);
+    let cumulative = model.add_cumulative(capacity, [(a, 1), (b, 2)]);
+    model.set_constraint_name(cumulative, "resource");
+    let precedence = model.add_le(start_a + 3, start_b);
+    model.only_enforce_if(precedence, [!present]);
+    model.add_no_overlap(&[a, b]);
+
+    let vars: Vec<_> = model
+        .variables()
+        .map(|view| (view.var, view.name.to_owned(), view.domain))
+        .collect();
+    assert_eq!(
+        vec![
+            (start_a, "start a".to_owned(), Domain::new(0, 10)),
+            (start_b, "start b".to_owned(), Domain::new(0, 10)),
+            (present.into(), "present".to_owned(), Domain::new(0, 1)),
+            (
+                capacity,
+                "capacity".to_owned(),
+                Domain::from([(1, 2), (4, 4)])
+            ),
+        ],
+        vars
+    );
+
+    let kinds: Vec<_> = model.constraints().map(|view| view.kind).collect();
+    assert_eq!(
+        vec![
+            ConstraintKind::Interval,
+            ConstraintKind::Interval,
+            ConstraintKind::Cumulative,
+            ConstraintKind::Linear,
+            ConstraintKind::NoOverlap,
+        ],
+        kinds
+    );
+    let views: Vec<_> = model.constraints().collect();
+    let [interval_a, interval_b, resource, before, no_overlap] = views.as_slice() else {
+        return assert_eq!(5, views.len());
+    };
+    assert_eq!(vec![start_a], interval_a.vars);
+    assert!(interval_a.enforcement_literals.is_empty());
+    assert_eq!(vec![start_b], interval_b.vars);
+    assert_eq!(vec![present], interval_b.enforcement_literals);
+    assert_eq!(Constraint::from(cumulative), resource.constraint);
+    assert_eq!("resource", resource.name);
+    assert_eq!(vec![capacity], resource.vars);
+    assert_eq!(precedence, before.constraint);
+    assert_eq!(vec![start_a, start_b], before.vars);
+    assert_eq!(vec![!present], before.enforcement_literals);
+    assert!(no_overlap.vars.is_empty());
+    assert_eq!("", no_overlap.name);
+}
+
+#[test]
+fn literals_count_as_their_variable() -> Result<(), Box<dyn std::error::Error>> {
+    let mut model = CpModelBuilder::default();
+    let [x, y, z] = [(); 3].map(|()| model.new_bool_var());
+    model.add_or([!x, y, !y]);
+    model.add_exactly_one([z, x]);
+    model.add_allowed_assignments([x, z], [[0, 1], [1, 0]])?;
+
+    let vars: Vec<_> = model.constraints().map(|view| view.vars).collect();
+    let [x, y, z] = [x, y, z].map(IntVar::from);
+    assert_eq!(vec![vec![x, y], vec![x, z], vec![x, z]], vars);
+    Ok(())
+}
+
+#[test]
+fn lookups_by_name() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var_with_name([(0, 5)], "x");
+    let y = model.new_int_var_with_name([(0, 5)], "y");
+    let shadow = model.new_int_var_with_name([(0, 5)], "x");
+    let first = model.add_le(x, y);
+    model.set_constraint_name(first, "order");
+    let second = model.add_le(shadow, y);
+    model.set_constraint_name(second, "order");
+
+    assert_eq!(Some(x), model.var_by_name("x"));
+    assert_eq!(Some(y), model.var_by_name("y"));
+    assert_eq!(None, model.var_by_name("z"));
+    assert_eq!(Some(first), model.constraint_by_name("order"));
+    assert_eq!(None, model.constraint_by_name(""));
+    assert_eq!(None, model.constraint_by_name("disorder"));
+}
+
+#[test]
+fn counts_per_kind() {
+    let mut model = CpModelBuilder::default();
+    assert!(model.constraint_counts().is_empty());
+    let [x, y] = [(); 2].map(|()| model.new_int_var([(0, 5)]));
+    let b = model.new_bool_var();
+    model.add_all_different([x, y]);
+    model.add_le(x, y);
+    model.add_ne(x, 3);
+    model.add_and([b]);
+    model.add_max_eq(x, [y, b.into()]);
+
+    let counts: Vec<_> = model.constraint_counts().into_iter().collect();
+    assert_eq!(
+        vec![
+            (ConstraintKind::BoolAnd, 1),
+            (ConstraintKind::LinMax, 1),
+            (ConstraintKind::Linear, 2),
+            (ConstraintKind::AllDiff, 1),
+        ],
+        counts
+    );
+}
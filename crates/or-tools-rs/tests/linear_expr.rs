@@ -0,0 +1,187 @@
+use or_tools::builder::{CpModelBuilder, DomainReduction, Error, LinearExpr, VariableSelection};
+use or_tools::proto::{self, CpSolverStatus};
+
+/// Sorted `(var, coeff)` terms and domain of a linear constraint.
+type Linear = (Vec<(i32, i64)>, Vec<i64>);
+
+/// Returns the sorted terms and the domain of the last linear
+/// constraint of the model.
+fn linear_terms(model: &CpModelBuilder) -> Option<Linear> {
+    match model.proto().constraints.last()?.constraint.as_ref()? {
+        proto::constraint_proto::Constraint::Linear(linear) => {
+            let mut terms: Vec<_> = linear
+                .vars
+                .iter()
+                .copied()
+                .zip(linear.coeffs.clone())
+                .collect();
+            terms.sort_unstable();
+            Some((terms, linear.domain.clone()))
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn operators_on_variables() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 10)]);
+    let y = model.new_int_var([(0, 10)]);
+    let b = model.new_bool_var();
+
+    model.add_le(2 * x + 3 * y, 10);
+    assert_eq!(
+        Some((vec![(0, 2), (1, 3)], vec![i64::MIN, 10])),
+        linear_terms(&model)
+    );
+
+    model.add_eq(x - 5, -y + b * 4);
+    assert_eq!(
+        Some((vec![(0, 1), (1, 1), (2, -4)], vec![5, 5])),
+        linear_terms(&model)
+    );
+
+    // a negated literal stands for `1 - b`, so this is `b - x >= -5`
+    model.add_ge(7 - !b, 1 + x);
+    assert_eq!(
+        Some((vec![(0, -1), (2, 1)], vec![-5, i64::MAX])),
+        linear_terms(&model)
+    );
+}
+
+#[test]
+fn sums() {
+    let mut model = CpModelBuilder::default();
+    let vars: Vec<_> = (0..4).map(|_| model.new_int_var([(0, 3)])).collect();
+    let total: LinearExpr = vars.iter().map(|&v| 2 * v).sum();
+    model.add_eq(total.clone(), 12);
+    model.add_eq(
+        LinearExpr::weighted_sum(vars.iter().copied(), [1, -1, 1, -1]),
+        0,
+    );
+    model.maximize(LinearExpr::sum(vars.iter().take(2).copied()));
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    let values: Vec<_> = vars.iter().map(|v| v.solution_value(&response)).collect();
+    assert_eq!(12, total.solution_value(&response));
+    assert_eq!(6, values.iter().sum::<i64>());
+    assert_eq!(
+        values.iter().step_by(2).sum::<i64>(),
+        values.iter().skip(1).step_by(2).sum::<i64>()
+    );
+    assert!((response.objective_value - 6.0).abs() < 1e-6);
+}
+
+#[test]
+fn canonical_constraints() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 10)]);
+    let y = model.new_int_var([(0, 10)]);
+    let b = model.new_bool_var();
+
+    model.add_le(y + x + x - y + b + !b, 7);
+    // `b + !b` is `b + 1 - b`
+    assert_eq!(
+        Some((vec![(0, 2)], vec![i64::MIN, 6])),
+        linear_terms(&model)
+    );
+
+    model.maximize(3 * y - x - y + x - 2 * y);
+    assert_eq!(
+        Some(0),
+        model.proto().objective.as_ref().map(|o| o.vars.len())
+    );
+}
+
+#[test]
+fn overflows() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 10)]);
+
+    let expr = LinearExpr::from((i64::MAX / 2 + 1, x));
+    assert_eq!(Some(Error::Overflow), expr.clone().checked_mul(2).err());
+    assert_eq!(
+        Some(Error::Overflow),
+        (expr.clone() * 2).checked_add(0).err()
+    );
+    assert_eq!(
+        Some(Error::Overflow),
+        (-LinearExpr::from(i64::MIN)).checked_sub(0).err()
+    );
+    assert!(expr.clone().checked_mul(-2).is_ok());
+
+    let mut merged = expr.clone() + expr;
+    assert_eq!(Err(Error::Overflow), merged.canonicalize());
+    model.add_le(merged, 0);
+    assert_eq!(Error::Overflow.to_string(), model.validate_cp_model());
+    assert_eq!(model.solve().status(), CpSolverStatus::ModelInvalid);
+}
+
+#[test]
+fn overflowing_objective_invalidates_the_model() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 10)]);
+    model.add_le(x, 5);
+    assert_ne!(Error::Overflow.to_string(), model.validate_cp_model());
+
+    model.maximize([(i64::MIN, x), (-1, x)]);
+    assert_eq!(Error::Overflow.to_string(), model.validate_cp_model());
+    assert_eq!(model.solve().status(), CpSolverStatus::ModelInvalid);
+}
+
+#[test]
+fn overflows_follow_the_live_model() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 10)]);
+    let huge = LinearExpr::from((i64::MAX, x)) * 2;
+
+    let max = model.add_max_eq(x, [huge.clone(), LinearExpr::from(3)]);
+    assert_eq!(Error::Overflow.to_string(), model.validate_cp_model());
+    model.disable(max);
+    assert_eq!("", model.validate_cp_model());
+    model.enable(max);
+    assert_eq!(Error::Overflow.to_string(), model.validate_cp_model());
+    model.disable(max);
+
+    model.minimize(huge.clone());
+    assert_eq!(Error::Overflow.to_string(), model.validate_cp_model());
+    model.maximize(x);
+    assert_eq!("", model.validate_cp_model());
+
+    let task = model.new_interval_var(x, huge.clone(), 5);
+    assert_eq!(model.solve().status(), CpSolverStatus::ModelInvalid);
+    model.disable(task.constraint());
+    assert_eq!("", model.validate_cp_model());
+
+    // search strategies cannot be removed
+    model.add_decision_strategy(
+        [huge],
+        VariableSelection::ChooseFirst,
+        DomainReduction::SelectMinValue,
+    );
+    assert_eq!(Error::Overflow.to_string(), model.validate_cp_model());
+}
+
+#[test]
+fn solution_values_do_not_overflow() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(-10, 10)]);
+    let y = model.new_int_var([(-10, 10)]);
+    let response = proto::CpSolverResponse {
+        solution: vec![10, -10],
+        ..Default::default()
+    };
+
+    let exact = LinearExpr::from([(i64::MAX / 10, x), (i64::MAX / 10, y)]) + 3;
+    assert_eq!(Ok(3), exact.checked_solution_value(&response));
+    assert_eq!(3, exact.solution_value(&response));
+
+    let high = LinearExpr::from((i64::MAX / 5, x));
+    assert_eq!(Err(Error::Overflow), high.checked_solution_value(&response));
+    assert_eq!(i64::MAX, high.solution_value(&response));
+
+    let low = LinearExpr::from((i64::MAX, y)) - 1;
+    assert_eq!(Err(Error::Overflow), low.checked_solution_value(&response));
+    assert_eq!(i64::MIN, low.solution_value(&response));
+}
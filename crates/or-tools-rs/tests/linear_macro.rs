@@ -0,0 +1,85 @@
+use or_tools::builder::CpModelBuilder;
+use or_tools::linear;
+use or_tools::proto::{self, CpSolverStatus};
+
+fn domain(model: &CpModelBuilder, constraint: usize) -> Option<Vec<i64>> {
+    match model
+        .proto()
+        .constraints
+        .get(constraint)?
+        .constraint
+        .as_ref()?
+    {
+        proto::constraint_proto::Constraint::Linear(linear) => Some(linear.domain.clone()),
+        _ => None,
+    }
+}
+
+#[test]
+fn comparisons() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 10)]);
+    linear!(model, x <= 5);
+    linear!(model, x < 5);
+    linear!(model, x >= 5);
+    linear!(model, x > 5);
+    linear!(model, x == 5);
+    linear!(model, 3 <= x + 1 <= 8);
+    linear!(model, 8 >= x - 1 >= (2 - 5));
+    assert_eq!(Some(vec![i64::MIN, 5]), domain(&model, 0));
+    assert_eq!(Some(vec![i64::MIN, 4]), domain(&model, 1));
+    assert_eq!(Some(vec![5, i64::MAX]), domain(&model, 2));
+    assert_eq!(Some(vec![6, i64::MAX]), domain(&model, 3));
+    assert_eq!(Some(vec![5, 5]), domain(&model, 4));
+    assert_eq!(Some(vec![2, 7]), domain(&model, 5));
+    assert_eq!(Some(vec![-2, 9]), domain(&model, 6));
+}
+
+#[test]
+fn options() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 10)]);
+    let b = model.new_bool_var();
+    let name = String::from("upper");
+    let upper = linear!(model, x <= 3, name = name, if b);
+    let lower = linear!(&mut model, x >= 8, if !b, name = "lower",);
+    assert_eq!("upper", model.constraint_name(upper));
+    assert_eq!("lower", model.constraint_name(lower));
+    let enforcement = |c: usize| {
+        model
+            .proto()
+            .constraints
+            .get(c)
+            .map(|c| c.enforcement_literal.clone())
+    };
+    assert_eq!(Some(vec![1]), enforcement(0));
+    assert_eq!(Some(vec![-2]), enforcement(1));
+
+    model.maximize(x);
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(10, x.solution_value(&response));
+    assert!(!b.solution_value(&response));
+}
+
+/// Counts the evaluations of the model expression of `linear!`.
+fn counted<'a>(calls: &mut usize, model: &'a mut CpModelBuilder) -> &'a mut CpModelBuilder {
+    *calls += 1;
+    model
+}
+
+#[test]
+fn model_is_evaluated_once() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 10)]);
+    let b = model.new_bool_var();
+    let mut calls = 0;
+    let cst = linear!(counted(&mut calls, &mut model), 2 <= x <= 5, name = "x", if b);
+    assert_eq!(1, calls);
+    assert_eq!("x", model.constraint_name(cst));
+    assert_eq!(Some(vec![2, 5]), domain(&model, 0));
+
+    let by_ref = &mut model;
+    linear!(by_ref, x != 3);
+    assert_eq!(Some(vec![i64::MIN, 2, 4, i64::MAX]), domain(&model, 1));
+}
@@ -0,0 +1,76 @@
+use or_tools::builder::{CpModelBuilder, Error};
+use or_tools::proto::{self, CpSolverStatus};
+use prost::Message;
+
+#[test]
+fn extend_deserialized_model() -> Result<(), Box<dyn std::error::Error>> {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var_with_name([(0, 10)], "x");
+    let y = model.new_int_var_with_name([(0, 10)], "y");
+    let use_y = model.new_bool_var_with_name("use y");
+    let task = model.new_fixed_size_interval_var(x, 2);
+    model.set_constraint_name(task.constraint(), "task");
+    let sum = model.add_le(x + y, 12);
+    model.set_constraint_name(sum, "sum");
+    model.only_enforce_if(sum, [use_y]);
+    let bytes = model.into_proto().encode_to_vec();
+
+    let mut model = CpModelBuilder::from_proto(proto::CpModelProto::decode(bytes.as_slice())?)?;
+    assert_eq!(Some(x), model.var_by_name("x"));
+    assert_eq!(Some(y), model.int_var(1));
+    assert_eq!(Some(use_y), model.bool_var_by_name("use y"));
+    assert_eq!(None, model.bool_var_by_name("x"));
+    assert_eq!(Some(task), model.interval_var_by_name("task"));
+    assert_eq!(None, model.interval_var_by_name("sum"));
+    assert_eq!(Some(sum), model.constraint(1));
+
+    let (Some(x), Some(y), Some(use_y), Some(task)) = (
+        model.var_by_name("x"),
+        model.var_by_name("y"),
+        model.bool_var_by_name("use y"),
+        model.interval_var_by_name("task"),
+    ) else {
+        return Err("missing handles".into());
+    };
+    let end = model.new_int_var([(0, 10)]);
+    model.add_eq(model.interval_end(task), end);
+    model.add_and([use_y]);
+    model.maximize(end + 2 * y);
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(2, x.solution_value(&response));
+    assert_eq!(10, y.solution_value(&response));
+    assert_eq!(4, end.solution_value(&response));
+    Ok(())
+}
+
+#[test]
+fn constants_are_shared_with_loaded_model() -> Result<(), Box<dyn std::error::Error>> {
+    let mut model = CpModelBuilder::default();
+    let named = model.new_int_var_with_name([(3, 3)], "three");
+    let three = model.new_constant(3);
+    let one = model.true_literal();
+
+    let mut model = CpModelBuilder::from_proto(model.into_proto())?;
+    assert_ne!(named, model.new_constant(3));
+    assert_eq!(three, model.new_constant(3));
+    assert_eq!(one, model.true_literal());
+    assert_eq!(3, model.proto().variables.len());
+    Ok(())
+}
+
+#[test]
+fn invalid_model_is_rejected() {
+    let mut proto = proto::CpModelProto::default();
+    proto.constraints.push(proto::ConstraintProto {
+        constraint: Some(proto::constraint_proto::Constraint::BoolOr(
+            proto::BoolArgumentProto { literals: vec![0] },
+        )),
+        ..Default::default()
+    });
+    assert!(matches!(
+        CpModelBuilder::from_proto(proto),
+        Err(Error::InvalidModel(message)) if !message.is_empty()
+    ));
+}
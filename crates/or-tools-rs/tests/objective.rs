@@ -0,0 +1,123 @@
+use or_tools::builder::{CpModelBuilder, LinearExpr, Sense};
+use or_tools::proto::{CpSolverStatus, SatParameters};
+
+#[test]
+fn float_knapsack() {
+    let weights = [3, 4, 2, 5];
+    let values = [1.25, 1.5, 0.8, 2.05];
+    let mut model = CpModelBuilder::default();
+    let items: Vec<_> = weights.iter().map(|_| model.new_bool_var()).collect();
+    model.add_le(
+        items
+            .iter()
+            .zip(weights)
+            .map(|(&item, weight)| (weight, item))
+            .collect::<LinearExpr>(),
+        7,
+    );
+    let terms: Vec<_> = items
+        .iter()
+        .zip(values)
+        .map(|(&item, value)| (value, item.into()))
+        .collect();
+    model.maximize_float(&terms, 0.1);
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    // items 2 and 3 beat items 0 and 1 by 0.1
+    let chosen: Vec<_> = items.iter().map(|i| i.solution_value(&response)).collect();
+    assert_eq!(vec![false, false, true, true], chosen);
+    assert!((model.float_objective_value(&response) - 2.95).abs() < 1e-9);
+}
+
+#[test]
+fn integer_objective_replaces_float_objective() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 10)]);
+    model.minimize_float(&[(0.5, x)], 0.);
+    model.maximize(x);
+    assert!(model.proto().floating_point_objective.is_none());
+    model.minimize_float(&[(0.5, x)], 0.);
+    assert!(model.proto().objective.is_none());
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(0, x.solution_value(&response));
+}
+
+#[test]
+fn float_objective_with_negated_literals() {
+    let mut model = CpModelBuilder::default();
+    let b = model.new_bool_var();
+    let c = model.new_bool_var();
+    // `0.5 * !b` is `0.5 - 0.5 * b`
+    model.maximize_float(&[(0.5, (!b).into()), (2., c.into())], 1.);
+    let objective = model.proto().floating_point_objective.clone();
+    assert_eq!(Some(vec![0, 1]), objective.as_ref().map(|o| o.vars.clone()));
+    assert_eq!(
+        Some(vec![-0.5, 2.]),
+        objective.as_ref().map(|o| o.coeffs.clone())
+    );
+    assert!(objective.is_some_and(|o| (o.offset - 1.5).abs() < 1e-9));
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert!(!b.solution_value(&response));
+    assert!(c.solution_value(&response));
+    assert!((model.float_objective_value(&response) - 3.5).abs() < 1e-9);
+}
+
+#[test]
+fn prioritized_objectives() {
+    // each job goes to one of two machines; machine 1 is faster but
+    // costs more, and the jobs should be late as rarely as possible
+    let durations = [(4, 2), (3, 2), (5, 3)];
+    let deadline = 6;
+    let mut model = CpModelBuilder::default();
+    let on_fast: Vec<_> = durations.iter().map(|_| model.new_bool_var()).collect();
+
+    let mut slow_load = LinearExpr::default();
+    let mut fast_load = LinearExpr::default();
+    for (&(slow, fast), &on_fast) in durations.iter().zip(&on_fast) {
+        slow_load += LinearExpr::from(slow) - (slow, on_fast);
+        fast_load += (fast, on_fast);
+    }
+    let late_slow = model.new_bool_var();
+    let late_fast = model.new_bool_var();
+    let slow_on_time = model.add_le(slow_load, deadline);
+    model.only_enforce_if(slow_on_time, [!late_slow]);
+    let fast_on_time = model.add_le(fast_load, deadline);
+    model.only_enforce_if(fast_on_time, [!late_fast]);
+    let late = LinearExpr::from(late_slow) + late_fast;
+    let cost: LinearExpr = on_fast.iter().copied().collect();
+    model.maximize(cost.clone());
+
+    let solution = model.solve_lexicographic(
+        &[
+            (late.clone(), Sense::Minimize),
+            (cost.clone(), Sense::Minimize),
+        ],
+        &SatParameters::default(),
+    );
+    assert_eq!(solution.response.status(), CpSolverStatus::Optimal);
+    // no job is late, and one job on the fast machine is not enough
+    assert_eq!(vec![0, 2], solution.objective_values);
+    assert_eq!(0, late.solution_value(&solution.response));
+    assert_eq!(2, cost.solution_value(&solution.response));
+    // the builder still has its own objective
+    assert!(model.proto().objective.is_some());
+    assert!(model.proto().solution_hint.is_none());
+}
+
+#[test]
+fn lexicographic_stops_on_infeasible_stage() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 3)]);
+    model.add_ge(x, 4);
+    let solution = model.solve_lexicographic(
+        &[(LinearExpr::from(x), Sense::Minimize)],
+        &SatParameters::default(),
+    );
+    assert_eq!(solution.response.status(), CpSolverStatus::Infeasible);
+    assert!(solution.objective_values.is_empty());
+}
@@ -0,0 +1,108 @@
+use or_tools::builder::{BoolVar, CpModelBuilder, IntVar, IntervalVar, LinearExpr};
+use or_tools::proto::CpSolverStatus;
+
+struct Rectangle {
+    x: IntVar,
+    y: IntVar,
+    width: i64,
+    height: i64,
+    x_interval: IntervalVar,
+    y_interval: IntervalVar,
+}
+
+fn new_rectangle(
+    model: &mut CpModelBuilder,
+    (width, height): (i64, i64),
+    (max_x, max_y): (i64, i64),
+    present: BoolVar,
+) -> Rectangle {
+    let x = model.new_int_var([(0, max_x - width)]);
+    let x_end = model.new_int_var([(width, max_x)]);
+    let y = model.new_int_var([(0, max_y - height)]);
+    let y_end = model.new_int_var([(height, max_y)]);
+    let x_interval = model.new_optional_interval_var(x, width, x_end, present);
+    let y_interval = model.new_optional_interval_var(y, height, y_end, present);
+    Rectangle {
+        x,
+        y,
+        width,
+        height,
+        x_interval,
+        y_interval,
+    }
+}
+
+fn overlap(a: &Rectangle, b: &Rectangle, response: &or_tools::proto::CpSolverResponse) -> bool {
+    let (ax, ay) = (a.x.solution_value(response), a.y.solution_value(response));
+    let (bx, by) = (b.x.solution_value(response), b.y.solution_value(response));
+    ax < bx + b.width && bx < ax + a.width && ay < by + b.height && by < ay + a.height
+}
+
+#[test]
+fn strip_packing() {
+    let mut model = CpModelBuilder::default();
+    let strip_width = 5;
+    let max_height = 10;
+    let present = model.new_bool_var();
+    model.add_and([present]);
+
+    let rectangles: Vec<_> = [(3, 2), (2, 3), (2, 2), (3, 1)]
+        .into_iter()
+        .map(|size| new_rectangle(&mut model, size, (strip_width, max_height), present))
+        .collect();
+    let intervals: Vec<_> = rectangles
+        .iter()
+        .map(|r| (r.x_interval, r.y_interval))
+        .collect();
+    model.add_no_overlap_2d(&intervals);
+
+    let height = model.new_int_var([(0, max_height)]);
+    model.add_max_eq(
+        height,
+        rectangles.iter().map(|r| LinearExpr::from(r.y) + r.height),
+    );
+    model.minimize(height);
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    // total area is 19, so a strip of width 5 needs a height of at least 4
+    assert_eq!(4, height.solution_value(&response));
+    for (i, a) in rectangles.iter().enumerate() {
+        for b in rectangles.iter().skip(i + 1) {
+            assert!(!overlap(a, b, &response));
+        }
+    }
+}
+
+#[test]
+fn optional_rectangles() {
+    let mut model = CpModelBuilder::default();
+    let no_overlap = model.add_no_overlap_2d(&[]);
+
+    let mut rectangles = Vec::new();
+    let mut presences = Vec::new();
+    for _ in 0..5 {
+        let present = model.new_bool_var();
+        let rectangle = new_rectangle(&mut model, (2, 2), (4, 4), present);
+        model.add_no_overlap_2d_rectangle(no_overlap, rectangle.x_interval, rectangle.y_interval);
+        rectangles.push(rectangle);
+        presences.push(present);
+    }
+    model.maximize(presences.iter().copied().collect::<LinearExpr>());
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    // only four 2x2 squares fit in a 4x4 square
+    assert!((response.objective_value - 4.0).abs() < 1e-6);
+    let placed: Vec<_> = rectangles
+        .iter()
+        .zip(&presences)
+        .filter(|(_, p)| p.solution_value(&response))
+        .map(|(r, _)| r)
+        .collect();
+    for (i, a) in placed.iter().enumerate() {
+        for b in placed.iter().skip(i + 1) {
+            assert!(!overlap(a, b, &response));
+        }
+    }
+}
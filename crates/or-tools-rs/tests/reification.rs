@@ -0,0 +1,93 @@
+use or_tools::Domain;
+use or_tools::builder::{CpModelBuilder, Error, LinearExpr};
+use or_tools::linear;
+use or_tools::proto::CpSolverStatus;
+
+#[test]
+fn count_occurrences() {
+    let mut model = CpModelBuilder::default();
+    let vars: Vec<_> = (0..5).map(|_| model.new_int_var([(0, 4)])).collect();
+    let threes: LinearExpr = vars.iter().map(|&v| model.new_reified_eq(v, 3)).sum();
+    let small: LinearExpr = vars.iter().map(|&v| model.new_reified_le(v, 1)).sum();
+    model.add_eq(threes, 2);
+    model.add_eq(small, 3);
+    model.maximize(LinearExpr::sum(vars.iter().copied()));
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    let mut values: Vec<_> = vars.iter().map(|v| v.solution_value(&response)).collect();
+    values.sort_unstable();
+    assert_eq!(vec![1, 1, 1, 3, 3], values);
+}
+
+#[test]
+fn indicators_match_solutions() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(-5, 5)]);
+    let y = model.new_int_var([(-5, 5)]);
+    let checks = [
+        (
+            model.new_reified_eq(x, y),
+            (|x, y| x == y) as fn(i64, i64) -> bool,
+        ),
+        (model.new_reified_ne(x, y), |x, y| x != y),
+        (model.new_reified_le(x + 1, y), |x, y| x < y),
+        (model.new_reified_ge(2 * x, y), |x, y| 2 * x >= y),
+        (
+            model.new_reified_in_domain(x - y, Domain::from_values([-3, 0, 4])),
+            |x, y| [-3, 0, 4].contains(&(x - y)),
+        ),
+    ];
+    let band = model.reify(|m| linear!(m, -2 <= x - 2 * y <= 3)).ok();
+    assert!(band.is_some());
+
+    for (x_value, y_value) in [(-5, 5), (0, 0), (2, -2), (3, -1), (4, 4)] {
+        let mut fixed = model.clone();
+        fixed.add_eq(x, x_value);
+        fixed.add_eq(y, y_value);
+        let response = fixed.solve();
+        assert_eq!(response.status(), CpSolverStatus::Optimal);
+        for (literal, check) in &checks {
+            assert_eq!(check(x_value, y_value), literal.solution_value(&response));
+        }
+        assert_eq!(
+            Some((-2..=3).contains(&(x_value - 2 * y_value))),
+            band.map(|b| b.solution_value(&response))
+        );
+    }
+}
+
+#[test]
+fn not_reifiable() {
+    let mut model = CpModelBuilder::default();
+    let b = model.new_bool_var();
+    let x = model.new_int_var([(0, 3)]);
+    let unchanged = model.clone();
+    assert_eq!(
+        Some(Error::NotReifiable),
+        model.reify(|m| m.add_and([b])).err()
+    );
+    assert_eq!(
+        Some(Error::NotReifiable),
+        model.reify(|m| linear!(m, x >= 2, if b)).err()
+    );
+    assert_eq!(
+        Some(Error::NotReifiable),
+        model
+            .reify(|m| {
+                let y = m.new_int_var([(0, 3)]);
+                let two = m.new_constant(2);
+                m.add_max_eq(y, [x, two])
+            })
+            .err()
+    );
+    assert_eq!(unchanged.proto(), model.proto());
+
+    // the constant removed with the failed constraint is created again
+    let two = model.new_constant(2);
+    assert_eq!(
+        Some(&[2, 2][..]),
+        model.proto().variables.last().map(|v| &v.domain[..])
+    );
+    assert_eq!(two, model.new_constant(2));
+}
@@ -0,0 +1,83 @@
+use or_tools::builder::{CpModelBuilder, LinearExpr};
+use or_tools::proto::CpSolverStatus;
+
+fn distance(positions: &[i64], a: i32, b: i32) -> i64 {
+    let position = |node: i32| {
+        usize::try_from(node)
+            .ok()
+            .and_then(|node| positions.get(node))
+            .copied()
+            .unwrap_or_default()
+    };
+    (position(a) - position(b)).abs()
+}
+
+#[test]
+fn traveling_salesman() {
+    let positions = [0, 1, 3, 6];
+    let mut model = CpModelBuilder::default();
+    let mut arcs = Vec::new();
+    let mut cost = LinearExpr::default();
+    for tail in 0..4 {
+        for head in 0..4 {
+            if tail != head {
+                let literal = model.new_bool_var();
+                cost += (distance(&positions, tail, head), literal);
+                arcs.push((tail, head, literal));
+            }
+        }
+    }
+    let circuit = model.add_circuit(arcs);
+    model.minimize(cost);
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    // on a line, the best tour goes to the end and comes back
+    assert!((response.objective_value - 12.0).abs() < 1e-6);
+
+    let tour = model.circuit_tour(circuit, &response);
+    assert_eq!(4, tour.len());
+    assert_eq!(Some(&0), tour.first());
+    let length: i64 = tour
+        .iter()
+        .zip(tour.iter().cycle().skip(1))
+        .map(|(&a, &b)| distance(&positions, a, b))
+        .sum();
+    assert_eq!(12, length);
+}
+
+#[test]
+fn vehicle_routing() {
+    let positions = [0, -3, -1, 2, 4];
+    let mut model = CpModelBuilder::default();
+    let mut arcs = Vec::new();
+    let mut cost = LinearExpr::default();
+    let mut from_depot = LinearExpr::default();
+    for tail in 0..5 {
+        for head in 0..5 {
+            if tail != head {
+                let literal = model.new_bool_var();
+                cost += (distance(&positions, tail, head), literal);
+                if tail == 0 {
+                    from_depot += literal;
+                }
+                arcs.push((tail, head, literal));
+            }
+        }
+    }
+    model.add_eq(from_depot, 2);
+    let routes = model.add_multiple_circuit(arcs);
+    model.minimize(cost);
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    // one vehicle serves the left side, the other one the right side
+    assert!((response.objective_value - 14.0).abs() < 1e-6);
+
+    let mut routes = model.multiple_circuit_routes(routes, &response);
+    assert_eq!(2, routes.len());
+    for route in &mut routes {
+        route.sort_unstable();
+    }
+    assert_eq!(vec![vec![1, 2], vec![3, 4]], routes);
+}
@@ -1,5 +1,5 @@
-use or_tools::builder::CpModelBuilder;
-use or_tools::proto::{CpSolverStatus, SatParameters};
+use or_tools::builder::{CpModelBuilder, DomainReduction, VariableSelection};
+use or_tools::proto::{CpSolverStatus, SatParameters, sat_parameters::SearchBranching};
 
 #[test]
 fn simple_sat_parameters() {
@@ -10,3 +10,31 @@ fn simple_sat_parameters() {
     assert_eq!(response.status(), CpSolverStatus::Optimal);
     let _x_value = x.solution_value(&response);
 }
+
+#[test]
+fn fixed_search_follows_decision_strategies() {
+    let mut model = CpModelBuilder::default();
+    let tasks: Vec<_> = (0..4).map(|_| model.new_int_var([(0, 3)])).collect();
+    model.add_all_different(tasks.iter().copied());
+    // the last task is decided first, to its largest value
+    model.add_decision_strategy(
+        tasks.iter().rev().take(1).copied(),
+        VariableSelection::ChooseFirst,
+        DomainReduction::SelectMaxValue,
+    );
+    model.add_decision_strategy(
+        tasks.iter().copied(),
+        VariableSelection::ChooseFirst,
+        DomainReduction::SelectMinValue,
+    );
+    assert_eq!(2, model.proto().search_strategy.len());
+
+    let mut params = SatParameters::default();
+    params.set_search_branching(SearchBranching::FixedSearch);
+    params.num_workers = Some(1);
+    params.cp_model_presolve = Some(false);
+    let response = model.solve_with_parameters(&params);
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    let values: Vec<_> = tasks.iter().map(|t| t.solution_value(&response)).collect();
+    assert_eq!(vec![0, 1, 2, 3], values);
+}
@@ -0,0 +1,119 @@
+use or_tools::Domain;
+use or_tools::builder::{CpModelBuilder, LinearExpr};
+use or_tools::proto::constraint_proto::Constraint;
+use or_tools::proto::{BoolArgumentProto, CpSolverStatus};
+
+#[test]
+fn domains_are_restricted_in_place() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 10)]);
+    let b = model.new_bool_var();
+    let c = model.new_bool_var();
+    model.add_le(x, 8);
+    let num_constraints = model.proto().constraints.len();
+
+    model.restrict_domain(x, [(-5, 3), (6, 20)]);
+    assert_eq!(Domain::from([(0, 3), (6, 10)]), model.var_domain(x));
+    model.fix(x, 7);
+    assert_eq!(Domain::new(7, 7), model.var_domain(x));
+    model.fix(!b, 1);
+    assert_eq!(Domain::new(0, 0), model.var_domain(b));
+    model.restrict_domain(!c, Domain::new(-3, 0));
+    assert_eq!(Domain::new(1, 1), model.var_domain(c));
+    assert_eq!(num_constraints, model.proto().constraints.len());
+
+    // the domain is kept, and an unsatisfiable constraint is added
+    model.fix(x, 8);
+    assert_eq!(Domain::new(7, 7), model.var_domain(x));
+    assert_eq!(num_constraints + 1, model.proto().constraints.len());
+    assert_eq!(
+        Some(&Constraint::BoolOr(BoolArgumentProto::default())),
+        model
+            .proto()
+            .constraints
+            .last()
+            .and_then(|c| c.constraint.as_ref())
+    );
+}
+
+#[test]
+fn job_must_start_at_given_time() {
+    let mut model = CpModelBuilder::default();
+    let durations = [3, 2, 4];
+    let starts: Vec<_> = durations
+        .iter()
+        .map(|_| model.new_int_var([(0, 20)]))
+        .collect();
+    let intervals: Vec<_> = starts
+        .iter()
+        .zip(durations)
+        .map(|(&start, duration)| model.new_fixed_size_interval_var(start, duration))
+        .collect();
+    model.add_no_overlap(&intervals);
+    let makespan = model.new_int_var([(0, 20)]);
+    for &interval in &intervals {
+        model.add_le(model.interval_end(interval), makespan);
+    }
+    model.minimize(makespan);
+
+    let Some(&last) = starts.last() else {
+        return;
+    };
+    let late = model.clone_with(|m| m.fix(last, 7));
+    let response = late.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(7, last.solution_value(&response));
+    assert_eq!(11, makespan.solution_value(&response));
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(9, makespan.solution_value(&response));
+
+    let impossible = model.clone_with(|m| m.fix(last, 30));
+    assert_eq!(impossible.solve().status(), CpSolverStatus::Infeasible);
+}
+
+#[test]
+fn unavailable_truck() {
+    let loads = [4, 3, 3, 2];
+    let capacities = [6, 5, 7];
+    let mut model = CpModelBuilder::default();
+    let trucks = loads.map(|_| model.new_int_var([(0, 2)]));
+    for (truck, capacity) in (0..).zip(capacities) {
+        let load: LinearExpr = trucks
+            .iter()
+            .zip(loads)
+            .map(|(&t, load)| load * model.new_reified_eq(t, truck))
+            .sum();
+        model.add_le(load, capacity);
+    }
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+
+    // the two other trucks cannot carry everything
+    let without_last = model.clone_with(|m| {
+        for truck in trucks {
+            m.restrict_domain(truck, [(0, 1)]);
+        }
+    });
+    assert_eq!(without_last.solve().status(), CpSolverStatus::Infeasible);
+
+    let [first, second, ..] = trucks;
+    let replan = model.clone_with(|m| {
+        m.fix_from_response([first], &response);
+        m.restrict_domain(
+            second,
+            Domain::from_values([first.solution_value(&response)]).complement(),
+        );
+    });
+    let replanned = replan.solve();
+    assert_eq!(replanned.status(), CpSolverStatus::Optimal);
+    assert_eq!(
+        first.solution_value(&response),
+        first.solution_value(&replanned)
+    );
+    assert_ne!(
+        first.solution_value(&replanned),
+        second.solution_value(&replanned)
+    );
+}
@@ -0,0 +1,192 @@
+use or_tools::builder::{CpModelBuilder, Error, IntervalVar, LinearExpr};
+use or_tools::proto::{self, CpSolverStatus};
+
+#[test]
+fn cumulative_with_optional_intervals() {
+    let mut model = CpModelBuilder::default();
+    let durations = [3, 2, 4, 1];
+    let demands = [2, 3, 1, 2];
+
+    let mut starts = Vec::new();
+    let mut presences = Vec::new();
+    let mut tasks = Vec::new();
+    for (duration, demand) in durations.into_iter().zip(demands) {
+        let start = model.new_int_var([(0, 20)]);
+        let end = model.new_int_var([(0, 20)]);
+        let present = model.new_bool_var();
+        let interval = model.new_optional_interval_var(start, duration, end, present);
+        starts.push(start);
+        presences.push(present);
+        tasks.push((interval, demand));
+    }
+    // scheduled tasks all start at 0, so their demands add up
+    for (&start, &present) in starts.iter().zip(&presences) {
+        let cst = model.add_eq(start, 0);
+        model.only_enforce_if(cst, [present]);
+    }
+    let cumulative = model.add_cumulative(4, tasks);
+    model.set_constraint_name(cumulative, "machine");
+    assert_eq!("machine", model.constraint_name(cumulative));
+    model.maximize(presences.iter().copied().collect::<LinearExpr>());
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    let scheduled: Vec<_> = presences
+        .iter()
+        .map(|p| p.solution_value(&response))
+        .collect();
+    let used: i64 = scheduled
+        .iter()
+        .zip(demands)
+        .filter(|(present, _)| **present)
+        .map(|(_, demand)| demand)
+        .sum();
+    assert!(used <= 4);
+    assert!((response.objective_value - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn cumulative_incremental_demands() {
+    let mut model = CpModelBuilder::default();
+    let present = model.new_bool_var();
+    model.add_and([present]);
+    let capacity = model.new_int_var([(0, 3)]);
+    let cumulative = model.add_cumulative::<i64>(capacity, []);
+
+    let mut ends = Vec::new();
+    for _ in 0..3 {
+        let start = model.new_int_var([(0, 10)]);
+        let end = model.new_int_var([(0, 10)]);
+        let interval = model.new_optional_interval_var(start, 2, end, present);
+        model.add_cumulative_demand(cumulative, interval, 2);
+        ends.push(end);
+    }
+    let makespan = model.new_int_var([(0, 10)]);
+    model.add_max_eq(makespan, ends);
+    model.minimize(makespan);
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    // with a capacity of at most 3, no two tasks can overlap
+    assert_eq!(6, makespan.solution_value(&response));
+}
+
+#[test]
+fn reservoir_battery_level() -> Result<(), Error> {
+    let mut model = CpModelBuilder::default();
+    let battery = model.add_reservoir(0, 10)?;
+    // the battery is used three times, at fixed times
+    for time in [2, 5, 8] {
+        model.add_reservoir_event(battery, time, -4, None);
+    }
+    // and can be charged by 6 at any time, at most 3 times
+    let mut charges = LinearExpr::default();
+    for _ in 0..3 {
+        let time = model.new_int_var([(0, 10)]);
+        let charge = model.new_bool_var();
+        model.add_reservoir_event(battery, time, 6, Some(charge));
+        charges += charge;
+    }
+    model.minimize(charges);
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    // 12 units are used, so two charges are needed
+    assert!((response.objective_value - 2.0).abs() < 1e-6);
+    Ok(())
+}
+
+#[test]
+fn reservoir_mixed_events() -> Result<(), Error> {
+    let mut model = CpModelBuilder::default();
+    let reservoir = model.add_reservoir(0, 10)?;
+    model.add_reservoir_event(reservoir, 0, 2, None);
+    let active = model.new_bool_var();
+    model.add_reservoir_event(reservoir, 1, 3, Some(active));
+    model.add_reservoir_event(reservoir, 2, -1, None);
+
+    let reservoir = model
+        .proto()
+        .constraints
+        .last()
+        .and_then(|cst| match &cst.constraint {
+            Some(proto::constraint_proto::Constraint::Reservoir(reservoir)) => Some(reservoir),
+            _ => None,
+        });
+    assert_eq!(Some(3), reservoir.map(|r| r.time_exprs.len()));
+    assert_eq!(Some(3), reservoir.map(|r| r.active_literals.len()));
+    assert!(model.validate_cp_model().is_empty());
+    Ok(())
+}
+
+#[test]
+fn reservoir_levels_contain_zero() {
+    let mut model = CpModelBuilder::default();
+    for (min_level, max_level) in [(1, 10), (-10, -1), (5, 2)] {
+        assert_eq!(
+            Err(Error::ReservoirLevels {
+                min_level,
+                max_level
+            }),
+            model.add_reservoir(min_level, max_level)
+        );
+    }
+    assert!(model.proto().constraints.is_empty());
+
+    assert!(model.add_reservoir(0, 0).is_ok());
+    assert!(model.add_reservoir(-3, 0).is_ok());
+    assert_eq!(2, model.proto().constraints.len());
+}
+
+#[test]
+fn job_shop_read_back() {
+    // two jobs of two tasks each, (machine, duration)
+    let jobs = [[(0, 3), (1, 2)], [(1, 4), (0, 1)]];
+    let horizon = 10;
+
+    let mut model = CpModelBuilder::default();
+    let mut machines = [Vec::new(), Vec::new()];
+    let mut tasks = Vec::new();
+    for job in jobs {
+        let mut previous: Option<IntervalVar> = None;
+        for (machine, duration) in job {
+            let start = model.new_int_var([(0, horizon)]);
+            let task = model.new_fixed_size_interval_var(start, duration);
+            if let Some(previous) = previous {
+                model.add_le(model.interval_end(previous), model.interval_start(task));
+            }
+            if let Some(tasks) = machines.get_mut(machine) {
+                tasks.push(task);
+            }
+            tasks.push((machine, task));
+            previous = Some(task);
+        }
+    }
+    for intervals in &machines {
+        model.add_no_overlap(intervals);
+    }
+    let makespan = model.new_int_var([(0, horizon)]);
+    let ends: Vec<_> = tasks.iter().map(|&(_, t)| model.interval_end(t)).collect();
+    model.add_max_eq(makespan, ends);
+    model.minimize(makespan);
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(6, makespan.solution_value(&response));
+    for (i, &(machine, a)) in tasks.iter().enumerate() {
+        assert!(a.is_present(&model, &response));
+        assert!(a.solution_end(&model, &response) <= 6);
+        assert_eq!(
+            a.solution_end(&model, &response),
+            a.solution_start(&model, &response) + a.solution_size(&model, &response)
+        );
+        for &(other_machine, b) in tasks.iter().skip(i + 1) {
+            if machine == other_machine {
+                assert!(
+                    a.solution_end(&model, &response) <= b.solution_start(&model, &response)
+                        || b.solution_end(&model, &response) <= a.solution_start(&model, &response)
+                );
+            }
+        }
+    }
+}
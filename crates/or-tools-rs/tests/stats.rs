@@ -0,0 +1,142 @@
+use or_tools::Domain;
+use or_tools::builder::{ConstraintKind, CpModelBuilder};
+use or_tools::stats::{DomainKind, ModelStats};
+
+#[test]
+fn empty_model() {
+    let stats = CpModelBuilder::default().model_stats();
+    assert_eq!(ModelStats::default(), stats);
+    assert_eq!(
+        "Satisfaction model '':\n#Variables: 0 (#bools: 0) (#ints: 0)\n",
+        stats.to_string()
+    );
+}
+
+#[test]
+fn scheduling_model() {
+    let mut model = CpModelBuilder::default();
+    let start_vars: Vec<_> = (0..3).map(|_| model.new_int_var([(0, 20)])).collect();
+    let present = model.new_bool_var();
+    let shift = model.new_int_var([(0, 4), (8, 12)]);
+    let one = model.new_constant(1);
+    let intervals: Vec<_> = start_vars
+        .iter()
+        .map(|&start| model.new_optional_interval_var(start, 2, start + 2, present))
+        .collect();
+    model.add_no_overlap(&intervals);
+    model.add_cumulative(2, intervals.iter().map(|&i| (i, 1)));
+    model.add_exactly_one([present, !present, present]);
+    let cst = model.add_le(shift + one, 10);
+    model.only_enforce_if(cst, [present]);
+    model.add_all_different(start_vars.iter().copied());
+    model.minimize_float(&[(0.5, shift)], 0.0);
+
+    let stats = model.model_stats();
+    assert!(stats.has_objective);
+    assert_eq!(1, stats.objective_size);
+    assert_eq!(
+        (6, 1, 5),
+        (stats.num_variables, stats.num_booleans, stats.num_integers)
+    );
+    assert_eq!(
+        vec![
+            (DomainKind::Fixed, 1),
+            (DomainKind::Boolean, 1),
+            (DomainKind::Interval, 3),
+            (DomainKind::Sparse, 1),
+        ],
+        stats
+            .variables_by_domain_kind
+            .into_iter()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![
+            (Domain::new(0, 1), 1),
+            (Domain::from([(0, 4), (8, 12)]), 1),
+            (Domain::new(0, 20), 3),
+            (Domain::new(1, 1), 1),
+        ],
+        stats.domains
+    );
+    assert_eq!(21, stats.largest_domain_size);
+    assert_eq!(
+        vec![
+            (ConstraintKind::ExactlyOne, 1),
+            (ConstraintKind::Linear, 1),
+            (ConstraintKind::AllDiff, 1),
+            (ConstraintKind::Interval, 3),
+            (ConstraintKind::NoOverlap, 1),
+            (ConstraintKind::Cumulative, 1),
+        ],
+        stats.constraints.into_iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![(ConstraintKind::Linear, 1), (ConstraintKind::Interval, 3)],
+        stats.enforced_constraints.into_iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![(ConstraintKind::ExactlyOne, 3)],
+        stats.num_literals.into_iter().collect::<Vec<_>>()
+    );
+    assert_eq!(2, stats.num_linear_terms);
+}
+
+#[test]
+fn display_summarizes_many_domains() {
+    let mut model = CpModelBuilder::default();
+    for max in 1..=12 {
+        model.new_int_var([(-max, max)]);
+    }
+    let b = model.new_bool_var();
+    model.add_or([b]);
+    let mut proto = model.into_proto();
+    proto.name = "many domains".into();
+    assert_eq!(
+        "Satisfaction model 'many domains':
+#Variables: 13 (#bools: 1) (#ints: 12)
+  - 13 different domains in [-12,12] with a largest size of 25
+#kBoolOr: 1 (#literals: 1)
+",
+        ModelStats::new(&proto).to_string()
+    );
+}
+
+#[test]
+fn disabled_constraints_are_counted_apart() {
+    let mut model = CpModelBuilder::default();
+    let [a, b, c] = [(); 3].map(|()| model.new_bool_var());
+    let clause = model.add_or([a, b]);
+    let linear = model.add_le(a + b + c, 2);
+    model.add_and([c]);
+    model.disable(clause);
+    model.disable(linear);
+    model.only_enforce_if(linear, [c]);
+
+    let stats = model.model_stats();
+    assert_eq!(2, stats.num_disabled);
+    assert_eq!(
+        vec![(ConstraintKind::BoolAnd, 1)],
+        stats
+            .constraints
+            .iter()
+            .map(|(&k, &n)| (k, n))
+            .collect::<Vec<_>>()
+    );
+    assert!(stats.enforced_constraints.is_empty());
+    assert_eq!(0, stats.num_linear_terms);
+    assert_eq!(
+        "Satisfaction model '':
+#Variables: 3 (#bools: 3) (#ints: 0)
+  - 3 Booleans in [0,1]
+#kBoolAnd: 1 (#literals: 1)
+#disabled: 2
+",
+        stats.to_string()
+    );
+
+    // the proto alone only has empty constraints
+    let from_proto = ModelStats::new(model.proto());
+    assert_eq!(0, from_proto.num_disabled);
+    assert_eq!(Some(&2), from_proto.constraints.get(&ConstraintKind::Empty));
+}
@@ -0,0 +1,65 @@
+use or_tools::builder::{CpModelBuilder, Error, LinearExpr};
+use or_tools::proto::CpSolverStatus;
+
+#[test]
+fn forbidden_assignments_with_expressions() -> Result<(), Error> {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 3)]);
+    let y = model.new_int_var([(0, 3)]);
+    // (x + y, x - y) cannot be (6, 0) or (5, 1)
+    model.add_forbidden_assignments(
+        [LinearExpr::from(x) + y, LinearExpr::from(x) - y],
+        [[6, 0], [5, 1]],
+    )?;
+    model.maximize(LinearExpr::from(x) + y);
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(2, x.solution_value(&response));
+    assert_eq!(3, y.solution_value(&response));
+    Ok(())
+}
+
+#[test]
+fn streamed_tuples() -> Result<(), Error> {
+    let mut model = CpModelBuilder::default();
+    let vars: Vec<_> = (0..3).map(|_| model.new_int_var([(0, 10)])).collect();
+    // all the permutations of (1, 2, 3)
+    let tuples = (1..=3).flat_map(|a| {
+        (1..=3).flat_map(move |b| {
+            (1..=3)
+                .filter(move |&c| a != b && b != c && a != c)
+                .map(move |c| [a, b, c])
+        })
+    });
+    model.add_allowed_assignments(vars.iter().copied(), tuples)?;
+    model.maximize(
+        [100, 10, 1]
+            .into_iter()
+            .zip(vars.iter().copied())
+            .collect::<LinearExpr>(),
+    );
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert!((response.objective_value - 321.0).abs() < 1e-6);
+    Ok(())
+}
+
+#[test]
+fn wrong_tuple_arity() {
+    let mut model = CpModelBuilder::default();
+    let x = model.new_int_var([(0, 10)]);
+    let y = model.new_int_var([(0, 10)]);
+    let tuples: [&[i64]; 3] = [&[1, 2], &[3, 4], &[5, 6, 7]];
+
+    let expected = Err(Error::TupleArity {
+        tuple: 2,
+        expected: 2,
+        actual: 3,
+    });
+    assert_eq!(expected, model.add_allowed_assignments([x, y], tuples));
+    assert_eq!(expected, model.add_forbidden_assignments([x, y], tuples));
+    // nothing is added to the model on error
+    assert!(model.proto().constraints.is_empty());
+}